@@ -0,0 +1,499 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    escape_name, strip_generic, CodeWriter, ResolvedProfile, RustOneOfVariant, RustType,
+    RustTypeKind, IDENTIFIER,
+};
+
+/// Conversions from the types of a spec version into the same-named types of another one.
+#[derive(Debug, Clone)]
+pub struct VersionConversions {
+    pub from_module: &'static str,
+    pub to_module: &'static str,
+    pub conversions: Vec<TypeConversion>,
+    /// Types generated for both versions that can't be converted automatically, with the reasons
+    pub manual: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeConversion {
+    pub type_name: String,
+    /// Whether some values can't be represented in the target version, making it a `TryFrom`
+    pub fallible: bool,
+    /// Expression building `Self` from a source value named `value`
+    pub body: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ConversionStatus {
+    Infallible,
+    Fallible,
+    Manual(Vec<String>),
+}
+
+struct ConversionContext {
+    from_module: &'static str,
+    /// Names of the types only generated for the source version
+    from_specific: HashSet<String>,
+    /// Names of the types only generated for the target version
+    to_specific: HashSet<String>,
+    statuses: HashMap<String, ConversionStatus>,
+}
+
+impl VersionConversions {
+    /// Resolves conversions for all types generated for both `from` and `to`, except the shared
+    /// ones which are the same type in both versions.
+    pub fn resolve(from: &ResolvedProfile, to: &ResolvedProfile, shared_types: &[String]) -> Self {
+        let version_specific = |resolved: &ResolvedProfile| {
+            resolved
+                .result
+                .all_types()
+                .filter(|rust_type| !shared_types.contains(&rust_type.name))
+                .map(|rust_type| rust_type.name.clone())
+                .collect::<HashSet<_>>()
+        };
+
+        let pairs = from
+            .result
+            .all_types()
+            .filter(|rust_type| !shared_types.contains(&rust_type.name))
+            .filter_map(|from_type| {
+                to.result
+                    .all_types()
+                    .find(|to_type| to_type.name == from_type.name)
+                    .map(|to_type| (from_type, to_type))
+            })
+            .collect::<Vec<_>>();
+
+        let mut context = ConversionContext {
+            from_module: from.profile.version.module_name(),
+            from_specific: version_specific(from),
+            to_specific: version_specific(to),
+            statuses: pairs
+                .iter()
+                .map(|(from_type, _)| (from_type.name.clone(), ConversionStatus::Infallible))
+                .collect(),
+        };
+
+        // Conversions depend on the conversions of the types they contain, so statuses are
+        // downgraded until none of them changes anymore
+        loop {
+            let mut changed = false;
+            for (from_type, to_type) in pairs.iter() {
+                let status = match context.type_conversion(from_type, to_type) {
+                    Ok(conversion) if conversion.fallible => ConversionStatus::Fallible,
+                    Ok(_) => ConversionStatus::Infallible,
+                    Err(reasons) => ConversionStatus::Manual(reasons),
+                };
+                if context.statuses[&from_type.name] != status {
+                    context.statuses.insert(from_type.name.clone(), status);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        let mut conversions = vec![];
+        let mut manual = vec![];
+        for (from_type, to_type) in pairs.iter() {
+            match context.type_conversion(from_type, to_type) {
+                Ok(conversion) => conversions.push(conversion),
+                Err(reasons) => manual.push((from_type.name.clone(), reasons)),
+            }
+        }
+
+        Self {
+            from_module: from.profile.version.module_name(),
+            to_module: to.profile.version.module_name(),
+            conversions,
+            manual,
+        }
+    }
+
+    pub fn render_stdout(&self, out: &mut CodeWriter) {
+        for (ind, conversion) in self.conversions.iter().enumerate() {
+            if ind != 0 {
+                writeln!(out);
+            }
+            conversion.render_stdout(out, self.from_module, self.to_module);
+        }
+    }
+
+    /// Prints the types needing manual conversion as comments.
+    pub fn render_report_stdout(&self, out: &mut CodeWriter) {
+        if self.manual.is_empty() {
+            return;
+        }
+
+        writeln!(
+            out,
+            "// These types need manual conversion from `{}` to `{}`:",
+            self.from_module, self.to_module
+        );
+        for (type_name, reasons) in self.manual.iter() {
+            writeln!(out, "// - `{}`: {}", type_name, reasons.join(", "));
+        }
+        writeln!(out);
+    }
+}
+
+impl TypeConversion {
+    fn render_stdout(&self, out: &mut CodeWriter, from_module: &str, to_module: &str) {
+        let from_type = format!("{}::{}", from_module, self.type_name);
+        let to_type = format!("{}::{}", to_module, self.type_name);
+
+        if self.fallible {
+            writeln!(out, "impl TryFrom<{from_type}> for {to_type} {{");
+            writeln!(out, "    type Error = VersionConversionError;");
+            writeln!(out);
+            writeln!(
+                out,
+                "    fn try_from(value: {from_type}) -> Result<Self, Self::Error> {{"
+            );
+        } else {
+            writeln!(out, "impl From<{from_type}> for {to_type} {{");
+            writeln!(out, "    fn from(value: {from_type}) -> Self {{");
+        }
+        for line in self.body.iter() {
+            writeln!(out, "        {line}");
+        }
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+}
+
+impl ConversionContext {
+    fn type_conversion(
+        &self,
+        from_type: &RustType,
+        to_type: &RustType,
+    ) -> Result<TypeConversion, Vec<String>> {
+        let mut reasons = vec![];
+        let mut fallible = false;
+        let mut body = vec![];
+
+        match (&from_type.content, &to_type.content) {
+            (RustTypeKind::Struct(from_content), RustTypeKind::Struct(to_content)) => {
+                let mut fields = vec![];
+                for to_field in to_content.fields.iter() {
+                    let from_field = match from_content
+                        .fields
+                        .iter()
+                        .find(|field| field.name == to_field.name)
+                    {
+                        Some(field) => field,
+                        None => {
+                            reasons.push(format!("field `{}` added", to_field.name));
+                            continue;
+                        }
+                    };
+
+                    match (&from_field.fixed, &to_field.fixed) {
+                        (Some(from_fixed), Some(to_fixed)) if from_fixed == to_fixed => {}
+                        (None, None) => {
+                            match self.convert_expr(
+                                &format!("value.{}", escape_name(&from_field.name)),
+                                &from_field.type_name_with_wrapping(),
+                                &to_field.type_name_with_wrapping(),
+                            ) {
+                                Some(Ok((expr, field_fallible))) => {
+                                    fallible |= field_fallible;
+                                    fields.push(format!(
+                                        "    {}: {},",
+                                        escape_name(&to_field.name),
+                                        expr
+                                    ));
+                                }
+                                Some(Err(type_name)) => reasons.push(format!(
+                                    "field `{}` contains `{}` which needs manual conversion",
+                                    to_field.name, type_name
+                                )),
+                                None => {
+                                    reasons.push(format!("field `{}` changed type", to_field.name))
+                                }
+                            }
+                        }
+                        _ => reasons.push(format!("field `{}` changed fixed value", to_field.name)),
+                    }
+                }
+                for from_field in from_content.fields.iter() {
+                    if !to_content
+                        .fields
+                        .iter()
+                        .any(|field| field.name == from_field.name)
+                    {
+                        reasons.push(format!("field `{}` removed", from_field.name));
+                    }
+                }
+
+                body.push(String::from("Self {"));
+                body.extend(fields);
+                body.push(String::from("}"));
+            }
+            (RustTypeKind::Enum(from_content), RustTypeKind::Enum(to_content)) => {
+                body.push(String::from("match value {"));
+                for variant in from_content.variants.iter() {
+                    let pattern =
+                        format!("{}::{}::{}", self.from_module, from_type.name, variant.name);
+                    if to_content
+                        .variants
+                        .iter()
+                        .any(|item| item.name == variant.name)
+                    {
+                        body.push(format!("    {} => Self::{},", pattern, variant.name));
+                    } else {
+                        fallible = true;
+                        body.push(format!(
+                            "    {} => return Err(VersionConversionError {{",
+                            pattern
+                        ));
+                        body.extend(variant_missing_error(&from_type.name, &variant.name));
+                        body.push(String::from("    }),"));
+                    }
+                }
+                body.push(String::from("}"));
+            }
+            (RustTypeKind::OneOf(from_content), RustTypeKind::OneOf(to_content)) => {
+                body.push(String::from("match value {"));
+                for variant in from_content.variants.iter() {
+                    let path =
+                        format!("{}::{}::{}", self.from_module, from_type.name, variant.name);
+                    match to_content
+                        .variants
+                        .iter()
+                        .find(|item| item.name == variant.name)
+                    {
+                        Some(to_variant)
+                            if variant.literal.is_some() || to_variant.literal.is_some() =>
+                        {
+                            if variant.literal == to_variant.literal {
+                                body.push(format!("    {} => Self::{},", path, variant.name));
+                            } else {
+                                reasons.push(format!("variant `{}` changed type", variant.name));
+                            }
+                        }
+                        Some(to_variant) => {
+                            let pattern = format!("{}(value)", path);
+                            match self.convert_expr(
+                                "value",
+                                &variant.type_name,
+                                &to_variant.type_name,
+                            ) {
+                                Some(Ok((expr, variant_fallible))) => {
+                                    fallible |= variant_fallible;
+                                    body.push(format!(
+                                        "    {} => Self::{}({}),",
+                                        pattern, variant.name, expr
+                                    ));
+                                }
+                                Some(Err(type_name)) => reasons.push(format!(
+                                    "variant `{}` contains `{}` which needs manual conversion",
+                                    variant.name, type_name
+                                )),
+                                None => {
+                                    reasons.push(format!("variant `{}` changed type", variant.name))
+                                }
+                            }
+                        }
+                        None => {
+                            // Unit variants standing for a `bool` value convert into a plain
+                            // `bool` variant, and the other way around for the values they stand
+                            // for.
+                            let plain_bool = |item: &&RustOneOfVariant| {
+                                item.literal.is_none() && item.type_name == "bool"
+                            };
+                            if let (Some(literal), Some(to_variant)) =
+                                (variant.literal, to_content.variants.iter().find(plain_bool))
+                            {
+                                body.push(format!(
+                                    "    {} => Self::{}({}),",
+                                    path, to_variant.name, literal
+                                ));
+                                continue;
+                            }
+                            if plain_bool(&variant) {
+                                for to_variant in to_content.variants.iter() {
+                                    if let Some(literal) = to_variant.literal {
+                                        body.push(format!(
+                                            "    {}({}) => Self::{},",
+                                            path, literal, to_variant.name
+                                        ));
+                                    }
+                                }
+                            }
+
+                            fallible = true;
+                            body.push(format!(
+                                "    {}{} => return Err(VersionConversionError {{",
+                                path,
+                                if variant.literal.is_some() { "" } else { "(_)" }
+                            ));
+                            body.extend(variant_missing_error(&from_type.name, &variant.name));
+                            body.push(String::from("    }),"));
+                        }
+                    }
+                }
+                body.push(String::from("}"));
+            }
+            (RustTypeKind::Wrapper(from_content), RustTypeKind::Wrapper(to_content)) => {
+                match self.convert_expr("value.0", &from_content.type_name, &to_content.type_name) {
+                    Some(Ok((expr, wrapper_fallible))) => {
+                        fallible = wrapper_fallible;
+                        body.push(format!("Self({expr})"));
+                    }
+                    Some(Err(type_name)) => reasons.push(format!(
+                        "wrapped type `{type_name}` needs manual conversion"
+                    )),
+                    None => reasons.push(String::from("wrapped type changed")),
+                }
+            }
+            (RustTypeKind::Unit(_), RustTypeKind::Unit(_)) => {
+                body.push(String::from("let _ = value;"));
+                body.push(String::from("Self"));
+            }
+            (from_content, to_content) => reasons.push(format!(
+                "changed from {} to {}",
+                from_content.kind_name(),
+                to_content.kind_name()
+            )),
+        }
+
+        if !reasons.is_empty() {
+            return Err(reasons);
+        }
+
+        if fallible {
+            if let Some(first) = body.first_mut() {
+                *first = format!("Ok({first}");
+            }
+            if let Some(last) = body.last_mut() {
+                last.push(')');
+            }
+        }
+
+        Ok(TypeConversion {
+            type_name: from_type.name.clone(),
+            fallible,
+            body,
+        })
+    }
+
+    /// Builds the expression converting `expr` of `from_type` into `to_type`, returning whether
+    /// it's fallible. Returns `None` if the types don't match, and the name of the contained type
+    /// that can't be converted if any.
+    fn convert_expr(
+        &self,
+        expr: &str,
+        from_type: &str,
+        to_type: &str,
+    ) -> Option<Result<(String, bool), String>> {
+        if from_type == to_type
+            && IDENTIFIER.find_iter(from_type).all(|identifier| {
+                !self.from_specific.contains(identifier.as_str())
+                    && !self.to_specific.contains(identifier.as_str())
+            })
+        {
+            return Some(Ok((expr.to_owned(), false)));
+        }
+
+        if let (Some(from_inner), Some(to_inner)) = (
+            strip_generic(from_type, "Arc"),
+            strip_generic(to_type, "Arc"),
+        ) {
+            return self
+                .convert_expr(
+                    &format!("std::sync::Arc::unwrap_or_clone({expr})"),
+                    from_inner,
+                    to_inner,
+                )
+                .map(|result| {
+                    result.map(|(inner, fallible)| {
+                        (format!("std::sync::Arc::new({inner})"), fallible)
+                    })
+                });
+        }
+
+        for wrapper in ["Vec", "Option"] {
+            if let (Some(from_inner), Some(to_inner)) = (
+                strip_generic(from_type, wrapper),
+                strip_generic(to_type, wrapper),
+            ) {
+                let (inner, fallible) = match self.convert_expr("item", from_inner, to_inner)? {
+                    Ok(inner) => inner,
+                    Err(type_name) => return Some(Err(type_name)),
+                };
+
+                let mapper = match (inner.as_str(), fallible) {
+                    ("item.into()", false) => String::from("Into::into"),
+                    ("item.try_into()?", true) => String::from("TryInto::try_into"),
+                    (_, false) => format!("|item| {inner}"),
+                    (_, true) => {
+                        format!("|item| -> Result<_, VersionConversionError> {{ Ok({inner}) }}")
+                    }
+                };
+
+                return Some(Ok((
+                    match (wrapper, fallible) {
+                        ("Vec", false) => format!("{expr}.into_iter().map({mapper}).collect()"),
+                        ("Vec", true) => {
+                            format!("{expr}.into_iter().map({mapper}).collect::<Result<_, _>>()?")
+                        }
+                        (_, false) => format!("{expr}.map({mapper})"),
+                        (_, true) => format!("{expr}.map({mapper}).transpose()?"),
+                    },
+                    fallible,
+                )));
+            }
+        }
+
+        if from_type != to_type
+            || !self.from_specific.contains(from_type)
+            || !self.to_specific.contains(to_type)
+        {
+            return None;
+        }
+
+        Some(match self.statuses.get(from_type)? {
+            ConversionStatus::Infallible => Ok((format!("{expr}.into()"), false)),
+            ConversionStatus::Fallible => Ok((format!("{expr}.try_into()?"), true)),
+            ConversionStatus::Manual(_) => Err(from_type.to_owned()),
+        })
+    }
+}
+
+fn variant_missing_error(type_name: &str, variant_name: &str) -> Vec<String> {
+    vec![
+        format!("        type_name: \"{type_name}\","),
+        format!(
+            "        reason: \"variant `{variant_name}` doesn't exist in the target version\","
+        ),
+    ]
+}
+
+/// Prints the error returned by fallible conversions between versions.
+pub fn print_version_conversion_error(out: &mut CodeWriter) {
+    writeln!(
+        out,
+        "/// Error converting a value into the shape of another spec version."
+    );
+    writeln!(
+        out,
+        "#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]"
+    );
+    writeln!(
+        out,
+        "#[error(\"`{{type_name}}` can't be converted to another spec version: {{reason}}\")]"
+    );
+    writeln!(out, "pub struct VersionConversionError {{");
+    writeln!(out, "    /// Name of the type being converted");
+    writeln!(out, "    pub type_name: &'static str,");
+    writeln!(
+        out,
+        "    /// Why the value can't be represented in the target version"
+    );
+    writeln!(out, "    pub reason: &'static str,");
+    writeln!(out, "}}");
+}
@@ -0,0 +1,370 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::Result;
+use clap::ValueEnum;
+
+use crate::spec::*;
+
+/// Reference graph of a specification, with an edge from each schema, method or error to
+/// everything it references.
+#[derive(Debug, Clone)]
+pub struct SchemaGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub name: String,
+    pub kind: NodeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: EdgeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Schema,
+    Method,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    /// Property, array item or any other plain reference
+    Ref,
+    /// `allOf` fragment kept as a nested `#[serde(flatten)]` field
+    AllOf,
+    /// `allOf` fragment whose properties are copied into the parent struct
+    Flatten,
+    OneOf,
+    Param,
+    Result,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+    Json,
+}
+
+impl SchemaGraph {
+    /// Builds the graph from `specs`, where `should_flatten` tells which `allOf` fragments are
+    /// flattened in generated code.
+    pub fn build(specs: &Specification, should_flatten: impl Fn(&str) -> bool) -> Self {
+        let mut graph = Self {
+            nodes: vec![],
+            edges: vec![],
+        };
+
+        for (name, schema) in specs.components.schemas.iter() {
+            graph.add_node(name, NodeKind::Schema);
+            graph.visit_schema(name, schema, EdgeKind::Ref, &should_flatten);
+        }
+
+        for method in specs.methods.iter() {
+            graph.add_node(&method.name, NodeKind::Method);
+            for param in method.params.iter() {
+                graph.visit_schema(
+                    &method.name,
+                    &param.schema,
+                    EdgeKind::Param,
+                    &should_flatten,
+                );
+            }
+            graph.visit_schema(
+                &method.name,
+                &method.result.schema,
+                EdgeKind::Result,
+                &should_flatten,
+            );
+            for error in method.errors.iter().flatten() {
+                graph.add_edge(&method.name, error.name(), EdgeKind::Error);
+            }
+        }
+
+        for (name, error) in specs.components.errors.iter() {
+            graph.add_node(name, NodeKind::Error);
+            if let ErrorType::Error(Error {
+                data: Some(data), ..
+            }) = error
+            {
+                graph.visit_schema(name, data, EdgeKind::Ref, &should_flatten);
+            }
+        }
+
+        graph
+    }
+
+    /// Gets the subgraph of everything that directly or transitively depends on `name`.
+    pub fn dependents_of(&self, name: &str) -> Result<Self> {
+        if !self.nodes.iter().any(|node| node.name == name) {
+            anyhow::bail!("Node `{}` not found in graph", name);
+        }
+
+        let mut dependents = HashSet::<&str>::new();
+        let mut queue = vec![name];
+        while let Some(current) = queue.pop() {
+            if !dependents.insert(current) {
+                continue;
+            }
+            for edge in self.edges.iter().filter(|edge| edge.to == current) {
+                queue.push(&edge.from);
+            }
+        }
+
+        Ok(self.subgraph(&dependents))
+    }
+
+    /// Gets the subgraph of everything `names` directly or transitively depend on.
+    pub fn dependencies_of(&self, names: &[&str]) -> Result<Self> {
+        for name in names.iter() {
+            if !self.nodes.iter().any(|node| &node.name == name) {
+                anyhow::bail!("Node `{}` not found in graph", name);
+            }
+        }
+
+        let mut dependencies = HashSet::<&str>::new();
+        let mut queue = names.to_vec();
+        while let Some(current) = queue.pop() {
+            if !dependencies.insert(current) {
+                continue;
+            }
+            for edge in self.edges.iter().filter(|edge| edge.from == current) {
+                queue.push(&edge.to);
+            }
+        }
+
+        Ok(self.subgraph(&dependencies))
+    }
+
+    pub fn render_stdout(&self, format: GraphFormat) {
+        match format {
+            GraphFormat::Dot => self.render_dot_stdout(),
+            GraphFormat::Mermaid => self.render_mermaid_stdout(),
+            GraphFormat::Json => self.render_json_stdout(),
+        }
+    }
+
+    fn render_dot_stdout(&self) {
+        println!("digraph schemas {{");
+        for node in self.nodes.iter() {
+            println!(
+                "    \"{}\" [shape={}];",
+                node.name,
+                match node.kind {
+                    NodeKind::Schema => "ellipse",
+                    NodeKind::Method => "box",
+                    NodeKind::Error => "octagon",
+                }
+            );
+        }
+        for edge in self.edges.iter() {
+            println!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"];",
+                edge.from,
+                edge.to,
+                edge.kind.as_str()
+            );
+        }
+        println!("}}");
+    }
+
+    fn render_mermaid_stdout(&self) {
+        println!("graph LR");
+        for node in self.nodes.iter() {
+            match node.kind {
+                NodeKind::Schema => println!("    {}", node.name),
+                NodeKind::Method => println!("    {}([{}])", node.name, node.name),
+                NodeKind::Error => println!("    {}{{{{{}}}}}", node.name, node.name),
+            }
+        }
+        for edge in self.edges.iter() {
+            println!("    {} -->|{}| {}", edge.from, edge.kind.as_str(), edge.to);
+        }
+    }
+
+    fn render_json_stdout(&self) {
+        let value = serde_json::json!({
+            "nodes": self
+                .nodes
+                .iter()
+                .map(|node| {
+                    serde_json::json!({
+                        "name": node.name,
+                        "kind": node.kind.as_str(),
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "edges": self
+                .edges
+                .iter()
+                .map(|edge| {
+                    serde_json::json!({
+                        "from": edge.from,
+                        "to": edge.to,
+                        "kind": edge.kind.as_str(),
+                    })
+                })
+                .collect::<Vec<_>>(),
+        });
+
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&value).expect("graph must be serializable")
+        );
+    }
+
+    fn subgraph(&self, names: &HashSet<&str>) -> Self {
+        Self {
+            nodes: self
+                .nodes
+                .iter()
+                .filter(|node| names.contains(node.name.as_str()))
+                .cloned()
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|edge| {
+                    names.contains(edge.from.as_str()) && names.contains(edge.to.as_str())
+                })
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn add_node(&mut self, name: &str, kind: NodeKind) {
+        self.nodes.push(GraphNode {
+            name: name.to_owned(),
+            kind,
+        });
+    }
+
+    fn add_edge(&mut self, from: &str, to: &str, kind: EdgeKind) {
+        let edge = GraphEdge {
+            from: from.to_owned(),
+            to: to.to_owned(),
+            kind,
+        };
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Adds edges from `from` to all schemas referenced in `schema`. Plain references get the
+    /// `ref_kind` edge kind, while `allOf` and `oneOf` entries get their own.
+    fn visit_schema(
+        &mut self,
+        from: &str,
+        schema: &Schema,
+        ref_kind: EdgeKind,
+        should_flatten: &impl Fn(&str) -> bool,
+    ) {
+        match schema {
+            Schema::Ref(reference) => self.add_edge(from, reference.name(), ref_kind),
+            Schema::OneOf(one_of) => {
+                for variant in one_of.one_of.iter() {
+                    match variant {
+                        Schema::Ref(reference) => {
+                            // Nullable types are plain references rather than enum variants, and
+                            // method params and results keep their own kind
+                            let kind = if one_of.nullable_variant().is_some()
+                                || ref_kind != EdgeKind::Ref
+                            {
+                                ref_kind
+                            } else {
+                                EdgeKind::OneOf
+                            };
+                            self.add_edge(from, reference.name(), kind);
+                        }
+                        _ => self.visit_schema(from, variant, ref_kind, should_flatten),
+                    }
+                }
+            }
+            Schema::AllOf(all_of) => {
+                for fragment in all_of.all_of.iter() {
+                    match fragment {
+                        Schema::Ref(reference) => {
+                            let kind = if should_flatten(reference.name()) {
+                                EdgeKind::Flatten
+                            } else {
+                                EdgeKind::AllOf
+                            };
+                            self.add_edge(from, reference.name(), kind);
+                        }
+                        _ => self.visit_schema(from, fragment, ref_kind, should_flatten),
+                    }
+                }
+            }
+            Schema::Primitive(Primitive::Object(object)) => {
+                for property in object.properties.values() {
+                    self.visit_schema(from, property, ref_kind, should_flatten);
+                }
+            }
+            Schema::Primitive(Primitive::Array(array)) => {
+                self.visit_schema(from, &array.items, ref_kind, should_flatten)
+            }
+            Schema::Primitive(_) => {}
+        }
+    }
+}
+
+impl NodeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Schema => "schema",
+            Self::Method => "method",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl EdgeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ref => "ref",
+            Self::AllOf => "allOf",
+            Self::Flatten => "flatten",
+            Self::OneOf => "oneOf",
+            Self::Param => "param",
+            Self::Result => "result",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "dot" => Self::Dot,
+            "mermaid" => Self::Mermaid,
+            "json" => Self::Json,
+            _ => anyhow::bail!("unknown graph format: {}", s),
+        })
+    }
+}
+
+impl ValueEnum for GraphFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Dot, Self::Mermaid, Self::Json]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        use clap::builder::PossibleValue;
+
+        match self {
+            Self::Dot => Some(PossibleValue::new("dot")),
+            Self::Mermaid => Some(PossibleValue::new("mermaid")),
+            Self::Json => Some(PossibleValue::new("json")),
+        }
+    }
+}
@@ -0,0 +1,2056 @@
+use anyhow::Result;
+
+use crate::{
+    camel_to_snake_case, escape_name, get_rust_type_for_field, print_doc, print_match_arm, spec::*,
+    to_method_type_prefix, to_starknet_rs_doc, to_starknet_rs_name, CodeWriter, RustField,
+    RustFieldType, RustType, RustTypeKind, SerializerOverride, MAX_LINE_LENGTH,
+};
+
+/// JSON-RPC 2.0 envelope types wrapping the request and response types of all methods.
+#[derive(Debug, Clone)]
+pub struct JsonRpcEnvelopes {
+    pub methods: Vec<EnvelopeMethod>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnvelopeMethod {
+    /// Method name in the specification, e.g. `starknet_getBlockWithTxHashes`
+    pub name: String,
+    /// Variant name of the method in all envelope enums, e.g. `GetBlockWithTxHashes`
+    pub variant_name: String,
+    pub request_type: String,
+    pub result_type: RustFieldType,
+}
+
+impl EnvelopeMethod {
+    /// Renders what's needed to decode the result of the method from a `serde_json::Value`
+    /// named `result`, returning whether it has to be decoded as a `Field` wrapping the result
+    /// instead of directly.
+    fn render_result_decoder_stdout(&self, out: &mut CodeWriter, indent_spaces: usize) -> bool {
+        let indent = " ".repeat(indent_spaces);
+
+        match &self.result_type.serializer {
+            Some(serializer) => {
+                if matches!(serializer, SerializerOverride::SerdeAs(_)) {
+                    writeln!(out, "{indent}#[serde_as]");
+                }
+                writeln!(out, "{indent}#[derive(Deserialize)]");
+                writeln!(out, "{indent}#[serde(transparent)]");
+                writeln!(
+                    out,
+                    "{}struct Field({} {});",
+                    indent,
+                    serializer.attribute(),
+                    self.result_type.type_name
+                );
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl JsonRpcEnvelopes {
+    /// Resolves the envelopes for all methods in `specs`. Inline result schemas rely on the
+    /// response types generated for them.
+    pub fn resolve(specs: &Specification) -> Result<Self> {
+        let methods = specs
+            .methods
+            .iter()
+            .map(|method| {
+                let variant_name = to_method_type_prefix(&method.name);
+                let result_type = match &method.result.schema {
+                    Schema::Ref(_) => get_rust_type_for_field(&method.result.schema, specs)?,
+                    _ => RustFieldType {
+                        type_name: format!("{variant_name}Response"),
+                        serializer: None,
+                    },
+                };
+
+                Ok(EnvelopeMethod {
+                    name: method.name.clone(),
+                    request_type: format!("{variant_name}Request"),
+                    variant_name,
+                    result_type,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { methods })
+    }
+
+    pub fn render_stdout(&self, out: &mut CodeWriter) {
+        self.render_types_stdout(out);
+        writeln!(out);
+        self.render_method_impl_stdout(out);
+        writeln!(out);
+        self.render_request_impls_stdout(out);
+        writeln!(out);
+        self.render_result_impls_stdout(out);
+        writeln!(out);
+        self.render_response_impls_stdout(out);
+    }
+
+    fn render_types_stdout(&self, out: &mut CodeWriter) {
+        print_doc(
+            out,
+            "Id of a JSON-RPC request, echoed back in its response. Errors detected before the id \
+            of a request could be read, like parse errors, are answered with a `null` id.",
+            0,
+        );
+        writeln!(
+            out,
+            "#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]"
+        );
+        writeln!(out, "#[serde(untagged)]");
+        writeln!(out, "pub enum JsonRpcId {{");
+        writeln!(out, "    Number(i64),");
+        writeln!(out, "    String(String),");
+        writeln!(out, "    Null,");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(out, "Methods of the specification.", 0);
+        writeln!(
+            out,
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]"
+        );
+        writeln!(out, "pub enum JsonRpcMethod {{");
+        for method in self.methods.iter() {
+            writeln!(out, "    #[serde(rename = \"{}\")]", method.name);
+            writeln!(out, "    {},", method.variant_name);
+        }
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(out, "Params of a JSON-RPC request, keyed by method.", 0);
+        writeln!(out, "#[derive(Debug, Clone)]");
+        writeln!(out, "pub enum JsonRpcRequestData {{");
+        for method in self.methods.iter() {
+            writeln!(out, "    {}({}),", method.variant_name, method.request_type);
+        }
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(out, "JSON-RPC 2.0 request.", 0);
+        writeln!(out, "#[derive(Debug, Clone)]");
+        writeln!(out, "pub struct JsonRpcRequest {{");
+        writeln!(
+            out,
+            "    /// Left out for notifications, which aren't answered."
+        );
+        writeln!(out, "    pub id: Option<JsonRpcId>,");
+        writeln!(out, "    pub data: JsonRpcRequestData,");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(
+            out,
+            "Result of a successful JSON-RPC call, keyed by method.",
+            0,
+        );
+        writeln!(out, "#[derive(Debug, Clone)]");
+        writeln!(out, "pub enum JsonRpcResult {{");
+        for method in self.methods.iter() {
+            writeln!(
+                out,
+                "    {}({}),",
+                method.variant_name, method.result_type.type_name
+            );
+        }
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(out, "Error object of a failed JSON-RPC call.", 0);
+        writeln!(out, "#[derive(Debug, Clone, Serialize, Deserialize)]");
+        writeln!(out, "pub struct JsonRpcError {{");
+        writeln!(out, "    pub code: i64,");
+        writeln!(out, "    pub message: String,");
+        writeln!(
+            out,
+            "    #[serde(default, skip_serializing_if = \"Option::is_none\")]"
+        );
+        writeln!(out, "    pub data: Option<serde_json::Value>,");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(
+            out,
+            "JSON-RPC 2.0 response. The result is left undecoded by default, as the method it \
+            answers is only known from the request.",
+            0,
+        );
+        writeln!(out, "#[derive(Debug, Clone)]");
+        writeln!(out, "pub enum JsonRpcResponse<T = serde_json::Value> {{");
+        writeln!(out, "    Success {{ id: JsonRpcId, result: T }},");
+        writeln!(out, "    Error {{ id: JsonRpcId, error: JsonRpcError }},");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(
+            out,
+            "Batch of JSON-RPC 2.0 requests sent in a single call.",
+            0,
+        );
+        writeln!(out, "#[derive(Debug, Clone, Serialize, Deserialize)]");
+        writeln!(out, "#[serde(transparent)]");
+        writeln!(
+            out,
+            "pub struct JsonRpcBatchRequest(pub Vec<JsonRpcRequest>);"
+        );
+        writeln!(out);
+
+        print_doc(
+            out,
+            "Responses to a batch of JSON-RPC 2.0 requests, which can come in any order.",
+            0,
+        );
+        writeln!(out, "#[derive(Debug, Clone, Serialize, Deserialize)]");
+        writeln!(out, "#[serde(transparent)]");
+        writeln!(
+            out,
+            "pub struct JsonRpcBatchResponse(pub Vec<JsonRpcResponse>);"
+        );
+    }
+
+    fn render_method_impl_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "impl JsonRpcMethod {{");
+        writeln!(out, "    /// Name of the method in the specification.");
+        writeln!(out, "    pub fn as_str(&self) -> &'static str {{");
+        writeln!(out, "        match self {{");
+        for method in self.methods.iter() {
+            print_match_arm(
+                out,
+                &format!("Self::{}", method.variant_name),
+                &format!("\"{}\"", method.name),
+                12,
+            );
+        }
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "    /// Decodes the result of a successful call to the method."
+        );
+        writeln!(out, "    pub fn decode_result(");
+        writeln!(out, "        &self,");
+        writeln!(out, "        result: serde_json::Value,");
+        writeln!(out, "    ) -> Result<JsonRpcResult, serde_json::Error> {{");
+        writeln!(out, "        Ok(match self {{");
+        for method in self.methods.iter() {
+            if method.result_type.serializer.is_some() {
+                writeln!(out, "            Self::{} => {{", method.variant_name);
+                method.render_result_decoder_stdout(out, 16);
+                writeln!(out);
+                writeln!(
+                    out,
+                    "                JsonRpcResult::{}(serde_json::from_value::<Field>(result)?.0)",
+                    method.variant_name
+                );
+                writeln!(out, "            }}");
+            } else {
+                print_match_arm(
+                    out,
+                    &format!("Self::{}", method.variant_name),
+                    &format!(
+                        "JsonRpcResult::{}(serde_json::from_value(result)?)",
+                        method.variant_name
+                    ),
+                    12,
+                );
+            }
+        }
+        writeln!(out, "        }})");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+
+    fn render_request_impls_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "impl JsonRpcRequestData {{");
+        writeln!(out, "    pub fn method(&self) -> JsonRpcMethod {{");
+        writeln!(out, "        match self {{");
+        for method in self.methods.iter() {
+            print_match_arm(
+                out,
+                &format!("Self::{}(_)", method.variant_name),
+                &format!("JsonRpcMethod::{}", method.variant_name),
+                12,
+            );
+        }
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl Serialize for JsonRpcRequest {{");
+        writeln!(
+            out,
+            "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        );
+        writeln!(out, "        use serde::ser::SerializeStruct;");
+        writeln!(out);
+        writeln!(
+            out,
+            "        let mut state = serializer.serialize_struct(\"JsonRpcRequest\", 4)?;"
+        );
+        writeln!(out, "        state.serialize_field(\"jsonrpc\", \"2.0\")?;");
+        writeln!(out, "        if let Some(id) = &self.id {{");
+        writeln!(out, "            state.serialize_field(\"id\", id)?;");
+        writeln!(out, "        }}");
+        writeln!(
+            out,
+            "        state.serialize_field(\"method\", &self.data.method())?;"
+        );
+        writeln!(out, "        match &self.data {{");
+        for method in self.methods.iter() {
+            print_match_arm(
+                out,
+                &format!("JsonRpcRequestData::{}(params)", method.variant_name),
+                "state.serialize_field(\"params\", params)?",
+                12,
+            );
+        }
+        writeln!(out, "        }}");
+        writeln!(out, "        state.end()");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl<'de> Deserialize<'de> for JsonRpcRequest {{");
+        writeln!(out, "    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{");
+        writeln!(out, "        #[derive(Deserialize)]");
+        writeln!(out, "        struct Raw {{");
+        writeln!(out, "            jsonrpc: String,");
+        writeln!(
+            out,
+            "            #[serde(default, deserialize_with = \"present\")]"
+        );
+        writeln!(out, "            id: Option<JsonRpcId>,");
+        writeln!(out, "            method: JsonRpcMethod,");
+        writeln!(out, "            params: Option<serde_json::Value>,");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "        /// Keeps `null` ids apart from missing ones, which `Option` doesn't."
+        );
+        writeln!(out, "        fn present<'de, D: Deserializer<'de>>(");
+        writeln!(out, "            deserializer: D,");
+        writeln!(out, "        ) -> Result<Option<JsonRpcId>, D::Error> {{");
+        writeln!(
+            out,
+            "            JsonRpcId::deserialize(deserializer).map(Some)"
+        );
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        let raw = Raw::deserialize(deserializer)?;");
+        writeln!(out, "        if raw.jsonrpc != \"2.0\" {{");
+        writeln!(
+            out,
+            "            return Err(serde::de::Error::custom(\"unsupported JSON-RPC version\"));"
+        );
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "        // Params can be left out for methods without any"
+        );
+        writeln!(out, "        let params = raw");
+        writeln!(out, "            .params");
+        writeln!(
+            out,
+            "            .unwrap_or_else(|| serde_json::Value::Array(vec![]));"
+        );
+        writeln!(
+            out,
+            "        let error_mapper = |err| serde::de::Error::custom(format!(\"invalid params: {{}}\", err));"
+        );
+        writeln!(out);
+        writeln!(out, "        let data = match raw.method {{");
+        for method in self.methods.iter() {
+            print_match_arm(
+                out,
+                &format!("JsonRpcMethod::{}", method.variant_name),
+                &format!(
+                    "JsonRpcRequestData::{}(serde_json::from_value(params).map_err(error_mapper)?)",
+                    method.variant_name
+                ),
+                12,
+            );
+        }
+        writeln!(out, "        }};");
+        writeln!(out);
+        writeln!(out, "        Ok(Self {{ id: raw.id, data }})");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+
+    fn render_result_impls_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "impl JsonRpcResult {{");
+        writeln!(out, "    pub fn method(&self) -> JsonRpcMethod {{");
+        writeln!(out, "        match self {{");
+        for method in self.methods.iter() {
+            print_match_arm(
+                out,
+                &format!("Self::{}(_)", method.variant_name),
+                &format!("JsonRpcMethod::{}", method.variant_name),
+                12,
+            );
+        }
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl Serialize for JsonRpcResult {{");
+        writeln!(
+            out,
+            "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        );
+        writeln!(out, "        match self {{");
+        for method in self.methods.iter() {
+            match &method.result_type.serializer {
+                Some(serializer) => {
+                    writeln!(
+                        out,
+                        "            Self::{}(result) => {{",
+                        method.variant_name
+                    );
+                    if matches!(serializer, SerializerOverride::SerdeAs(_)) {
+                        writeln!(out, "                #[serde_as]");
+                    }
+                    writeln!(out, "                #[derive(Serialize)]");
+                    writeln!(out, "                #[serde(transparent)]");
+                    writeln!(
+                        out,
+                        "                struct Field<'a>({} &'a {});",
+                        serializer.attribute(),
+                        method.result_type.type_name
+                    );
+                    writeln!(out);
+                    writeln!(out, "                Field(result).serialize(serializer)");
+                    writeln!(out, "            }}");
+                }
+                None => print_match_arm(
+                    out,
+                    &format!("Self::{}(result)", method.variant_name),
+                    "result.serialize(serializer)",
+                    12,
+                ),
+            }
+        }
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+
+    fn render_response_impls_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "impl<T> JsonRpcResponse<T> {{");
+        writeln!(out, "    pub fn id(&self) -> &JsonRpcId {{");
+        writeln!(out, "        match self {{");
+        writeln!(
+            out,
+            "            Self::Success {{ id, .. }} | Self::Error {{ id, .. }} => id,"
+        );
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl JsonRpcResponse {{");
+        writeln!(
+            out,
+            "    /// Decodes the result of the response to a call to `method`."
+        );
+        writeln!(out, "    pub fn decode(");
+        writeln!(out, "        self,");
+        writeln!(out, "        method: JsonRpcMethod,");
+        writeln!(
+            out,
+            "    ) -> Result<JsonRpcResponse<JsonRpcResult>, serde_json::Error> {{"
+        );
+        writeln!(out, "        Ok(match self {{");
+        writeln!(
+            out,
+            "            Self::Success {{ id, result }} => JsonRpcResponse::Success {{"
+        );
+        writeln!(out, "                id,");
+        writeln!(
+            out,
+            "                result: method.decode_result(result)?,"
+        );
+        writeln!(out, "            }},");
+        writeln!(
+            out,
+            "            Self::Error {{ id, error }} => JsonRpcResponse::Error {{ id, error }},"
+        );
+        writeln!(out, "        }})");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl JsonRpcBatchResponse {{");
+        writeln!(out, "    /// Decodes the responses to `batch`, matched by id and returned in request order.");
+        writeln!(
+            out,
+            "    /// Notifications are skipped, as they aren't answered."
+        );
+        writeln!(out, "    pub fn decode(");
+        writeln!(out, "        self,");
+        writeln!(out, "        batch: &JsonRpcBatchRequest,");
+        writeln!(
+            out,
+            "    ) -> Result<Vec<JsonRpcResponse<JsonRpcResult>>, serde_json::Error> {{"
+        );
+        writeln!(out, "        let mut responses = self.0;");
+        writeln!(out);
+        writeln!(out, "        batch");
+        writeln!(out, "            .0");
+        writeln!(out, "            .iter()");
+        writeln!(
+            out,
+            "            .filter_map(|request| request.id.as_ref().map(|id| (id, request.data.method())))"
+        );
+        writeln!(out, "            .map(|(id, method)| {{");
+        writeln!(out, "                let position = responses");
+        writeln!(out, "                    .iter()");
+        writeln!(
+            out,
+            "                    .position(|response| response.id() == id)"
+        );
+        writeln!(out, "                    .ok_or_else(|| {{");
+        writeln!(
+            out,
+            "                        <serde_json::Error as serde::de::Error>::custom(format!("
+        );
+        writeln!(
+            out,
+            "                            \"missing response for request {{:?}}\","
+        );
+        writeln!(out, "                            id");
+        writeln!(out, "                        ))");
+        writeln!(out, "                    }})?;");
+        writeln!(out);
+        writeln!(
+            out,
+            "                responses.swap_remove(position).decode(method)"
+        );
+        writeln!(out, "            }})");
+        writeln!(out, "            .collect()");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(
+            out,
+            "impl<T: Serialize> Serialize for JsonRpcResponse<T> {{"
+        );
+        writeln!(
+            out,
+            "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        );
+        writeln!(out, "        use serde::ser::SerializeStruct;");
+        writeln!(out);
+        writeln!(
+            out,
+            "        let mut state = serializer.serialize_struct(\"JsonRpcResponse\", 3)?;"
+        );
+        writeln!(out, "        state.serialize_field(\"jsonrpc\", \"2.0\")?;");
+        writeln!(out, "        state.serialize_field(\"id\", self.id())?;");
+        writeln!(out, "        match self {{");
+        writeln!(out, "            Self::Success {{ result, .. }} => state.serialize_field(\"result\", result)?,");
+        writeln!(
+            out,
+            "            Self::Error {{ error, .. }} => state.serialize_field(\"error\", error)?,"
+        );
+        writeln!(out, "        }}");
+        writeln!(out, "        state.end()");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(
+            out,
+            "impl<'de, T: Deserialize<'de>> Deserialize<'de> for JsonRpcResponse<T> {{"
+        );
+        writeln!(out, "    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{");
+        writeln!(out, "        #[derive(Deserialize)]");
+        writeln!(
+            out,
+            "        #[serde(bound(deserialize = \"R: Deserialize<'de>\"))]"
+        );
+        writeln!(out, "        struct Raw<R> {{");
+        writeln!(out, "            jsonrpc: String,");
+        writeln!(out, "            id: JsonRpcId,");
+        writeln!(
+            out,
+            "            #[serde(default, deserialize_with = \"present\")]"
+        );
+        writeln!(out, "            result: Option<R>,");
+        writeln!(out, "            error: Option<JsonRpcError>,");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "        /// Keeps `null` results apart from missing ones, which `Option` doesn't."
+        );
+        writeln!(
+            out,
+            "        fn present<'de, D: Deserializer<'de>, R: Deserialize<'de>>("
+        );
+        writeln!(out, "            deserializer: D,");
+        writeln!(out, "        ) -> Result<Option<R>, D::Error> {{");
+        writeln!(out, "            R::deserialize(deserializer).map(Some)");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "        let raw = Raw::<T>::deserialize(deserializer)?;"
+        );
+        writeln!(out, "        if raw.jsonrpc != \"2.0\" {{");
+        writeln!(
+            out,
+            "            return Err(serde::de::Error::custom(\"unsupported JSON-RPC version\"));"
+        );
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        match (raw.result, raw.error) {{");
+        writeln!(
+            out,
+            "            (_, Some(error)) => Ok(Self::Error {{ id: raw.id, error }}),"
+        );
+        writeln!(
+            out,
+            "            (Some(result), None) => Ok(Self::Success {{ id: raw.id, result }}),"
+        );
+        writeln!(
+            out,
+            "            (None, None) => Err(serde::de::Error::missing_field(\"result\")),"
+        );
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+}
+
+/// Client implementing every method on top of a transport, using the JSON-RPC envelopes.
+#[derive(Debug, Clone)]
+pub struct JsonRpcClient {
+    pub methods: Vec<ClientMethod>,
+    /// Variant name and code of each `StarknetError` variant
+    pub error_codes: Vec<(String, i64)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClientMethod {
+    pub envelope: EnvelopeMethod,
+    /// Name of the client function, e.g. `get_block_with_tx_hashes`
+    pub fn_name: String,
+    pub summary: String,
+    /// Fields of the request type, empty for methods without params
+    pub params: Vec<RustField>,
+}
+
+impl JsonRpcClient {
+    /// Resolves the client for all methods in `envelopes`, with params taken from the request
+    /// types so that they match the generated `Ref` variants.
+    pub fn resolve(
+        specs: &Specification,
+        envelopes: &JsonRpcEnvelopes,
+        request_types: &[RustType],
+    ) -> Result<Self> {
+        let methods = specs
+            .methods
+            .iter()
+            .zip(envelopes.methods.iter())
+            .map(|(method, envelope)| {
+                let request_type = request_types
+                    .iter()
+                    .find(|item| item.name == envelope.request_type)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Request type `{}` not found", envelope.request_type)
+                    })?;
+                let params = match &request_type.content {
+                    RustTypeKind::Struct(content) => content.fields.clone(),
+                    RustTypeKind::Unit(_) => vec![],
+                    _ => anyhow::bail!(
+                        "Request type `{}` is not a struct or unit",
+                        envelope.request_type
+                    ),
+                };
+
+                Ok(ClientMethod {
+                    envelope: envelope.clone(),
+                    fn_name: camel_to_snake_case(method.name.trim_start_matches("starknet_")),
+                    summary: to_starknet_rs_doc(&method.summary, true),
+                    params,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let error_codes = specs
+            .components
+            .errors
+            .iter()
+            .map(|(name, error)| match error {
+                ErrorType::Error(error) => Ok((to_starknet_rs_name(name), error.code)),
+                ErrorType::Reference(_) => anyhow::bail!("Error redirection not implemented"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            methods,
+            error_codes,
+        })
+    }
+
+    /// Renders the client, along with the transport trait unless it's `shared` with other spec
+    /// versions.
+    pub fn render_stdout(&self, out: &mut CodeWriter, with_transport: bool) {
+        self.render_error_codes_stdout(out);
+        writeln!(out);
+        self.render_types_stdout(out, with_transport);
+        writeln!(out);
+        self.render_client_impl_stdout(out);
+    }
+
+    fn render_error_codes_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "impl StarknetError {{");
+        writeln!(out, "    /// Code of the error in the specification.");
+        writeln!(out, "    pub fn code(&self) -> i64 {{");
+        if self.error_codes.is_empty() {
+            writeln!(out, "        match *self {{}}");
+        } else {
+            writeln!(out, "        match *self {{");
+            for (name, code) in self.error_codes.iter() {
+                writeln!(out, "            Self::{name} => {code},");
+            }
+            writeln!(out, "        }}");
+        }
+        writeln!(out, "    }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "    /// Gets the error with `code` in the specification, if any."
+        );
+        writeln!(out, "    pub fn from_code(code: i64) -> Option<Self> {{");
+        writeln!(out, "        match code {{");
+        for (name, code) in self.error_codes.iter() {
+            writeln!(out, "            {code} => Some(Self::{name}),");
+        }
+        writeln!(out, "            _ => None,");
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+
+    fn render_types_stdout(&self, out: &mut CodeWriter, with_transport: bool) {
+        if with_transport {
+            print_json_rpc_transport(out);
+            writeln!(out);
+        }
+
+        print_doc(out, "Error of a [JsonRpcClient] call.", 0);
+        writeln!(out, "#[derive(Debug, thiserror::Error)]");
+        writeln!(out, "pub enum JsonRpcClientError<T> {{");
+        writeln!(out, "    /// The transport failed to send the request");
+        writeln!(out, "    #[error(transparent)]");
+        writeln!(out, "    Transport(T),");
+        writeln!(
+            out,
+            "    /// The node returned an error defined in the specification, along with its data"
+        );
+        writeln!(out, "    #[error(\"{{error}}\")]");
+        writeln!(out, "    Starknet {{");
+        writeln!(out, "        error: StarknetError,");
+        writeln!(out, "        data: Option<serde_json::Value>,");
+        writeln!(out, "    }},");
+        writeln!(
+            out,
+            "    /// The node returned an error not defined in the specification"
+        );
+        writeln!(
+            out,
+            "    #[error(\"JSON-RPC error {{}}: {{}}\", .0.code, .0.message)]"
+        );
+        writeln!(out, "    JsonRpc(JsonRpcError),");
+        writeln!(
+            out,
+            "    /// The request could not be encoded or the response decoded"
+        );
+        writeln!(out, "    #[error(transparent)]");
+        writeln!(out, "    Json(serde_json::Error),");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(
+            out,
+            "JSON-RPC client for all methods of the specification, sending requests through a \
+            [JsonRpcTransport].",
+            0,
+        );
+        writeln!(out, "#[derive(Debug)]");
+        writeln!(out, "pub struct JsonRpcClient<T> {{");
+        writeln!(out, "    transport: T,");
+        writeln!(out, "    next_id: std::sync::atomic::AtomicI64,");
+        writeln!(out, "}}");
+    }
+
+    fn render_client_impl_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "impl<T> JsonRpcClient<T> {{");
+        writeln!(out, "    pub fn new(transport: T) -> Self {{");
+        writeln!(out, "        Self {{");
+        writeln!(out, "            transport,");
+        writeln!(
+            out,
+            "            next_id: std::sync::atomic::AtomicI64::new(0),"
+        );
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out);
+        writeln!(out, "    pub fn transport(&self) -> &T {{");
+        writeln!(out, "        &self.transport");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl<T: JsonRpcTransport> JsonRpcClient<T> {{");
+        for method in self.methods.iter() {
+            method.render_stdout(out);
+            writeln!(out);
+        }
+
+        writeln!(out, "    async fn send_request<P: Serialize>(");
+        writeln!(out, "        &self,");
+        writeln!(out, "        method: JsonRpcMethod,");
+        writeln!(out, "        params: &P,");
+        writeln!(
+            out,
+            "    ) -> Result<serde_json::Value, JsonRpcClientError<T::Error>> {{"
+        );
+        writeln!(out, "        #[derive(Serialize)]");
+        writeln!(out, "        struct Request<'a, P> {{");
+        writeln!(out, "            jsonrpc: &'static str,");
+        writeln!(out, "            id: &'a JsonRpcId,");
+        writeln!(out, "            method: JsonRpcMethod,");
+        writeln!(out, "            params: &'a P,");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        let id = JsonRpcId::Number(");
+        writeln!(out, "            self.next_id");
+        writeln!(
+            out,
+            "                .fetch_add(1, std::sync::atomic::Ordering::Relaxed),"
+        );
+        writeln!(out, "        );");
+        writeln!(out, "        let request = serde_json::to_value(Request {{");
+        writeln!(out, "            jsonrpc: \"2.0\",");
+        writeln!(out, "            id: &id,");
+        writeln!(out, "            method,");
+        writeln!(out, "            params,");
+        writeln!(out, "        }})");
+        writeln!(out, "        .map_err(JsonRpcClientError::Json)?;");
+        writeln!(out);
+        writeln!(out, "        let response = self");
+        writeln!(out, "            .transport");
+        writeln!(out, "            .send(request)");
+        writeln!(out, "            .await");
+        writeln!(out, "            .map_err(JsonRpcClientError::Transport)?;");
+        writeln!(
+            out,
+            "        let response = serde_json::from_value::<JsonRpcResponse>(response)"
+        );
+        writeln!(out, "            .map_err(JsonRpcClientError::Json)?;");
+        writeln!(out);
+        writeln!(
+            out,
+            "        // Errors detected before the request id could be read have a `null` id"
+        );
+        writeln!(out, "        let null_error =");
+        writeln!(
+            out,
+            "            matches!(response, JsonRpcResponse::Error {{ .. }}) && response.id() == &JsonRpcId::Null;"
+        );
+        writeln!(out, "        if response.id() != &id && !null_error {{");
+        writeln!(
+            out,
+            "            return Err(JsonRpcClientError::Json(serde::de::Error::custom(format!("
+        );
+        writeln!(out, "                \"unexpected response id {{:?}}\",");
+        writeln!(out, "                response.id()");
+        writeln!(out, "            ))));");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        match response {{");
+        writeln!(
+            out,
+            "            JsonRpcResponse::Success {{ result, .. }} => Ok(result),"
+        );
+        writeln!(
+            out,
+            "            JsonRpcResponse::Error {{ error, .. }} => {{"
+        );
+        writeln!(
+            out,
+            "                Err(match StarknetError::from_code(error.code) {{"
+        );
+        writeln!(
+            out,
+            "                    Some(starknet_error) => JsonRpcClientError::Starknet {{"
+        );
+        writeln!(out, "                        error: starknet_error,");
+        writeln!(out, "                        data: error.data,");
+        writeln!(out, "                    }},");
+        writeln!(
+            out,
+            "                    None => JsonRpcClientError::JsonRpc(error),"
+        );
+        writeln!(out, "                }})");
+        writeln!(out, "            }}");
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+}
+
+impl ClientMethod {
+    fn render_stdout(&self, out: &mut CodeWriter) {
+        print_doc(out, &self.summary, 4);
+        let width = MAX_LINE_LENGTH - out.indent;
+
+        let signature = format!(
+            "    pub async fn {}(&self) -> Result<{}, JsonRpcClientError<T::Error>> {{",
+            escape_name(&self.fn_name),
+            self.envelope.result_type.type_name
+        );
+        if self.params.is_empty() && signature.len() <= width {
+            writeln!(out, "{signature}");
+        } else {
+            writeln!(out, "    pub async fn {}(", escape_name(&self.fn_name));
+            writeln!(out, "        &self,");
+            for param in self.params.iter() {
+                writeln!(
+                    out,
+                    "        {}: {},",
+                    escape_name(&param.name),
+                    param.ref_type_name(None)
+                );
+            }
+            writeln!(
+                out,
+                "    ) -> Result<{}, JsonRpcClientError<T::Error>> {{",
+                self.envelope.result_type.type_name
+            );
+        }
+
+        // Laid out like rustfmt does, which puts struct literals of up to 18 characters and
+        // chains of less than 60 on a single line
+        let names = self
+            .params
+            .iter()
+            .map(|param| escape_name(&param.name))
+            .collect::<Vec<_>>();
+        let params = names.join(", ");
+        if names.is_empty() {
+            writeln!(out, "        let params = {};", self.envelope.request_type);
+        } else if params.len() <= 18
+            && 8 + self.envelope.request_type.len() + params.len() + 24 <= width
+        {
+            writeln!(
+                out,
+                "        let params = {}Ref {{ {} }};",
+                self.envelope.request_type, params
+            );
+        } else {
+            writeln!(
+                out,
+                "        let params = {}Ref {{",
+                self.envelope.request_type
+            );
+            for name in names.iter() {
+                writeln!(out, "            {name},");
+            }
+            writeln!(out, "        }};");
+        }
+
+        let call = format!(
+            "self.send_request(JsonRpcMethod::{}, &params).await?",
+            self.envelope.variant_name
+        );
+        if call.len() < 60 {
+            writeln!(out, "        let result = {call};");
+        } else {
+            writeln!(out, "        let result = self");
+            writeln!(
+                out,
+                "            .send_request(JsonRpcMethod::{}, &params)",
+                self.envelope.variant_name
+            );
+            writeln!(out, "            .await?;");
+        }
+        writeln!(out);
+        if self.envelope.render_result_decoder_stdout(out, 8) {
+            writeln!(out, "        serde_json::from_value::<Field>(result)");
+            writeln!(out, "            .map(|field| field.0)");
+            writeln!(out, "            .map_err(JsonRpcClientError::Json)");
+        } else {
+            writeln!(
+                out,
+                "        serde_json::from_value(result).map_err(JsonRpcClientError::Json)"
+            );
+        }
+        writeln!(out, "    }}");
+    }
+}
+
+impl JsonRpcClient {
+    /// Renders `MockProvider`, a transport answering requests from queues of expectations set
+    /// for each method.
+    pub fn render_mock_provider_stdout(&self, out: &mut CodeWriter) {
+        let width = MAX_LINE_LENGTH - out.indent;
+
+        print_doc(
+            out,
+            "Expected call to a method of a [MockProvider]. Expectations of a method are used \
+            once each, in the order they were set.",
+            0,
+        );
+        writeln!(out, "pub struct MockExpectation<Req, Res> {{");
+        writeln!(
+            out,
+            "    matcher: Option<Box<dyn Fn(&Req) -> bool + Send>>,"
+        );
+        writeln!(
+            out,
+            "    responder: Option<Box<dyn FnOnce(Req) -> Result<Res, StarknetError> + Send>>,"
+        );
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(
+            out,
+            "Error returned by a [MockProvider] for requests it can't answer.",
+            0,
+        );
+        writeln!(out, "#[derive(Debug, thiserror::Error)]");
+        writeln!(out, "pub enum MockError {{");
+        writeln!(
+            out,
+            "    #[error(\"unexpected call to {{}}\", .0.as_str())]"
+        );
+        writeln!(out, "    UnexpectedCall(JsonRpcMethod),");
+        writeln!(
+            out,
+            "    #[error(\"request to {{}} does not match expectation\", .0.as_str())]"
+        );
+        writeln!(out, "    UnmatchedRequest(JsonRpcMethod),");
+        writeln!(
+            out,
+            "    #[error(\"no response set for call to {{}}\", .0.as_str())]"
+        );
+        writeln!(out, "    MissingResponse(JsonRpcMethod),");
+        writeln!(out, "    #[error(transparent)]");
+        writeln!(out, "    Json(serde_json::Error),");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(
+            out,
+            "Mock node for tests, used as the transport of a [JsonRpcClient]. Each call is \
+            answered by the next expectation set for its method, and dropping the provider \
+            panics if any expectation is left.",
+            0,
+        );
+        writeln!(out, "#[derive(Default)]");
+        writeln!(out, "pub struct MockProvider {{");
+        writeln!(out, "    expectations: std::sync::Mutex<MockExpectations>,");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "#[derive(Default)]");
+        writeln!(out, "struct MockExpectations {{");
+        for method in self.methods.iter() {
+            // Laid out like rustfmt does, breaking after the field name, or else inside the queue
+            // type
+            let expectation = format!(
+                "MockExpectation<{}, {}>",
+                method.envelope.request_type, method.envelope.result_type.type_name
+            );
+            let queue = format!("std::collections::VecDeque<{expectation}>");
+            if 4 + method.fn_name.len() + queue.len() + 3 <= width {
+                writeln!(out, "    {}: {},", method.fn_name, queue);
+            } else if 8 + queue.len() < width {
+                writeln!(out, "    {}:", method.fn_name);
+                writeln!(out, "        {queue},");
+            } else {
+                writeln!(out, "    {}: std::collections::VecDeque<", method.fn_name);
+                writeln!(out, "        {expectation},");
+                writeln!(out, "    >,");
+            }
+        }
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl<Req, Res> MockExpectation<Req, Res> {{");
+        writeln!(
+            out,
+            "    /// Only matches requests for which `matcher` returns `true`."
+        );
+        writeln!(
+            out,
+            "    pub fn with(&mut self, matcher: impl Fn(&Req) -> bool + Send + 'static) -> &mut Self {{"
+        );
+        writeln!(out, "        self.matcher = Some(Box::new(matcher));");
+        writeln!(out, "        self");
+        writeln!(out, "    }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "    /// Answers the request with the result or error returned by `responder`."
+        );
+        writeln!(out, "    pub fn returning(");
+        writeln!(out, "        &mut self,");
+        writeln!(
+            out,
+            "        responder: impl FnOnce(Req) -> Result<Res, StarknetError> + Send + 'static,"
+        );
+        writeln!(out, "    ) -> &mut Self {{");
+        writeln!(out, "        self.responder = Some(Box::new(responder));");
+        writeln!(out, "        self");
+        writeln!(out, "    }}");
+        writeln!(out);
+        writeln!(out, "    fn respond(");
+        writeln!(out, "        self,");
+        writeln!(out, "        method: JsonRpcMethod,");
+        writeln!(out, "        request: Req,");
+        writeln!(
+            out,
+            "    ) -> Result<Result<Res, StarknetError>, MockError> {{"
+        );
+        writeln!(out, "        if let Some(matcher) = &self.matcher {{");
+        writeln!(out, "            if !matcher(&request) {{");
+        writeln!(
+            out,
+            "                return Err(MockError::UnmatchedRequest(method));"
+        );
+        writeln!(out, "            }}");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        match self.responder {{");
+        writeln!(
+            out,
+            "            Some(responder) => Ok(responder(request)),"
+        );
+        writeln!(
+            out,
+            "            None => Err(MockError::MissingResponse(method)),"
+        );
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl MockProvider {{");
+        writeln!(out, "    pub fn new() -> Self {{");
+        writeln!(out, "        Self::default()");
+        writeln!(out, "    }}");
+        for method in self.methods.iter() {
+            writeln!(out);
+            let expectation = format!(
+                "&mut MockExpectation<{}, {}>",
+                method.envelope.request_type, method.envelope.result_type.type_name
+            );
+            let signature = format!(
+                "    pub fn expect_{}(&mut self) -> {} {{",
+                method.fn_name, expectation
+            );
+            if signature.len() <= width {
+                writeln!(out, "{signature}");
+            } else {
+                writeln!(out, "    pub fn expect_{}(", method.fn_name);
+                writeln!(out, "        &mut self,");
+                // rustfmt moves the brace to its own line unless it leaves four columns to spare
+                if 9 + expectation.len() + 2 + 4 <= width {
+                    writeln!(out, "    ) -> {expectation} {{");
+                } else {
+                    writeln!(out, "    ) -> {expectation}");
+                    writeln!(out, "    {{");
+                }
+            }
+            writeln!(out, "        let expectations = &mut self");
+            writeln!(out, "            .expectations");
+            writeln!(out, "            .get_mut()");
+            writeln!(
+                out,
+                "            .unwrap_or_else(std::sync::PoisonError::into_inner)"
+            );
+            writeln!(out, "            .{};", method.fn_name);
+            writeln!(out, "        expectations.push_back(MockExpectation {{");
+            writeln!(out, "            matcher: None,");
+            writeln!(out, "            responder: None,");
+            writeln!(out, "        }});");
+            writeln!(out, "        expectations.back_mut().unwrap()");
+            writeln!(out, "    }}");
+        }
+        writeln!(out);
+        writeln!(
+            out,
+            "    fn respond(&self, request: serde_json::Value) -> Result<serde_json::Value, MockError> {{"
+        );
+        writeln!(
+            out,
+            "        let request = serde_json::from_value::<JsonRpcRequest>(request).map_err(MockError::Json)?;"
+        );
+        writeln!(out, "        let method = request.data.method();");
+        writeln!(out, "        let mut expectations = self");
+        writeln!(out, "            .expectations");
+        writeln!(out, "            .lock()");
+        writeln!(
+            out,
+            "            .unwrap_or_else(std::sync::PoisonError::into_inner);"
+        );
+        writeln!(out);
+        writeln!(out, "        let result = match request.data {{");
+        for method in self.methods.iter() {
+            writeln!(
+                out,
+                "            JsonRpcRequestData::{}(params) => expectations",
+                method.envelope.variant_name
+            );
+            writeln!(out, "                .{}", method.fn_name);
+            writeln!(out, "                .pop_front()");
+            writeln!(
+                out,
+                "                .ok_or(MockError::UnexpectedCall(method))?"
+            );
+            writeln!(out, "                .respond(method, params)?");
+            writeln!(
+                out,
+                "                .map(JsonRpcResult::{}),",
+                method.envelope.variant_name
+            );
+        }
+        writeln!(out, "        }};");
+        writeln!(out);
+        writeln!(
+            out,
+            "        // Transports always answer, so notifications get the `null` id"
+        );
+        writeln!(
+            out,
+            "        let id = request.id.unwrap_or(JsonRpcId::Null);"
+        );
+        writeln!(out, "        let response = match result {{");
+        writeln!(
+            out,
+            "            Ok(result) => JsonRpcResponse::Success {{ id, result }},"
+        );
+        writeln!(out, "            Err(error) => JsonRpcResponse::Error {{");
+        writeln!(out, "                id,");
+        writeln!(out, "                error: JsonRpcError {{");
+        writeln!(out, "                    code: error.code(),");
+        writeln!(out, "                    message: error.to_string(),");
+        writeln!(out, "                    data: None,");
+        writeln!(out, "                }},");
+        writeln!(out, "            }},");
+        writeln!(out, "        }};");
+        writeln!(
+            out,
+            "        serde_json::to_value(response).map_err(MockError::Json)"
+        );
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl JsonRpcTransport for MockProvider {{");
+        writeln!(out, "    type Error = MockError;");
+        writeln!(out);
+        writeln!(out, "    fn send(");
+        writeln!(out, "        &self,");
+        writeln!(out, "        request: serde_json::Value,");
+        writeln!(
+            out,
+            "    ) -> impl std::future::Future<Output = Result<serde_json::Value, Self::Error>> + Send {{"
+        );
+        writeln!(out, "        std::future::ready(self.respond(request))");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl Drop for MockProvider {{");
+        writeln!(out, "    fn drop(&mut self) {{");
+        writeln!(out, "        if std::thread::panicking() {{");
+        writeln!(out, "            return;");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        let expectations = self");
+        writeln!(out, "            .expectations");
+        writeln!(out, "            .get_mut()");
+        writeln!(
+            out,
+            "            .unwrap_or_else(std::sync::PoisonError::into_inner);"
+        );
+        writeln!(out, "        let mut unsatisfied = vec![];");
+        for method in self.methods.iter() {
+            // rustfmt breaks chains of 60 characters or more
+            let pending = format!("expectations.{}.is_empty()", method.fn_name);
+            if pending.len() < 60 {
+                writeln!(out, "        if !{pending} {{");
+            } else {
+                writeln!(out, "        if !expectations");
+                writeln!(out, "            .{}", method.fn_name);
+                writeln!(out, "            .is_empty()");
+                writeln!(out, "        {{");
+            }
+            writeln!(
+                out,
+                "            unsatisfied.push(JsonRpcMethod::{}.as_str());",
+                method.envelope.variant_name
+            );
+            writeln!(out, "        }}");
+        }
+        writeln!(out);
+        writeln!(out, "        assert!(");
+        writeln!(out, "            unsatisfied.is_empty(),");
+        writeln!(out, "            \"unsatisfied expectations for {{}}\",");
+        writeln!(out, "            unsatisfied.join(\", \")");
+        writeln!(out, "        );");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+}
+
+/// Prints the transport trait used by generated clients.
+pub fn print_json_rpc_transport(out: &mut CodeWriter) {
+    print_doc(
+        out,
+        "Transport sending JSON-RPC requests to a node and returning its responses.",
+        0,
+    );
+    writeln!(out, "pub trait JsonRpcTransport {{");
+    writeln!(
+        out,
+        "    type Error: std::error::Error + Send + Sync + 'static;"
+    );
+    writeln!(out);
+    writeln!(out, "    fn send(");
+    writeln!(out, "        &self,");
+    writeln!(out, "        request: serde_json::Value,");
+    writeln!(
+        out,
+        "    ) -> impl std::future::Future<Output = Result<serde_json::Value, Self::Error>> + Send;"
+    );
+    writeln!(out, "}}");
+}
+
+/// Prints `RecordingTransport` and `ReplayTransport`, capturing exchanges with a node in JSON
+/// fixtures and answering from them.
+pub fn print_record_replay_transports(out: &mut CodeWriter) {
+    print_doc(
+        out,
+        "Exchanges with a node captured by a [RecordingTransport], to be answered by a \
+        [ReplayTransport].",
+        0,
+    );
+    writeln!(
+        out,
+        "#[derive(Debug, Clone, Default, Serialize, Deserialize)]"
+    );
+    writeln!(out, "pub struct JsonRpcFixture {{");
+    writeln!(out, "    pub exchanges: Vec<RecordedExchange>,");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    print_doc(
+        out,
+        "Request and response recorded in a [JsonRpcFixture]. Params are stored as serialized \
+        by the request type of the method, so requests match structurally regardless of how \
+        they were originally encoded.",
+        0,
+    );
+    writeln!(out, "#[derive(Debug, Clone, Serialize, Deserialize)]");
+    writeln!(out, "pub struct RecordedExchange {{");
+    writeln!(out, "    pub method: JsonRpcMethod,");
+    writeln!(out, "    pub params: serde_json::Value,");
+    writeln!(out, "    pub response: RecordedResponse,");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    writeln!(out, "#[derive(Debug, Clone, Serialize, Deserialize)]");
+    writeln!(out, "#[serde(rename_all = \"snake_case\")]");
+    writeln!(out, "pub enum RecordedResponse {{");
+    writeln!(out, "    Result(serde_json::Value),");
+    writeln!(out, "    Error(JsonRpcError),");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    print_doc(
+        out,
+        "Error reading, writing or answering from a [JsonRpcFixture].",
+        0,
+    );
+    writeln!(out, "#[derive(Debug, thiserror::Error)]");
+    writeln!(out, "pub enum FixtureError {{");
+    writeln!(out, "    #[error(transparent)]");
+    writeln!(out, "    Io(std::io::Error),");
+    writeln!(out, "    #[error(transparent)]");
+    writeln!(out, "    Json(serde_json::Error),");
+    writeln!(
+        out,
+        "    #[error(\"no recorded exchange left for request to {{}}\", .0.as_str())]"
+    );
+    writeln!(out, "    NoRecordedExchange(JsonRpcMethod),");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    print_doc(out, "Error of a [RecordingTransport].", 0);
+    writeln!(out, "#[derive(Debug, thiserror::Error)]");
+    writeln!(out, "pub enum RecordingError<T> {{");
+    writeln!(out, "    #[error(transparent)]");
+    writeln!(out, "    Transport(T),");
+    writeln!(out, "    #[error(transparent)]");
+    writeln!(out, "    Fixture(FixtureError),");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    print_doc(
+        out,
+        "Transport sending requests through another transport and recording each exchange to \
+        a fixture file, which is rewritten after every exchange. Results are checked to decode \
+        into the result type of their method before being recorded.",
+        0,
+    );
+    writeln!(out, "pub struct RecordingTransport<T> {{");
+    writeln!(out, "    inner: T,");
+    writeln!(out, "    path: std::path::PathBuf,");
+    writeln!(out, "    fixture: std::sync::Mutex<JsonRpcFixture>,");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    print_doc(
+        out,
+        "Transport answering requests from a [JsonRpcFixture]. Each recorded exchange is used \
+        once, in the order they were recorded.",
+        0,
+    );
+    writeln!(out, "pub struct ReplayTransport {{");
+    writeln!(
+        out,
+        "    exchanges: std::sync::Mutex<Vec<RecordedExchange>>,"
+    );
+    writeln!(out, "}}");
+    writeln!(out);
+
+    writeln!(out, "impl JsonRpcFixture {{");
+    writeln!(
+        out,
+        "    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, FixtureError> {{"
+    );
+    writeln!(
+        out,
+        "        let content = std::fs::read(path).map_err(FixtureError::Io)?;"
+    );
+    writeln!(
+        out,
+        "        serde_json::from_slice(&content).map_err(FixtureError::Json)"
+    );
+    writeln!(out, "    }}");
+    writeln!(out);
+    writeln!(
+        out,
+        "    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), FixtureError> {{"
+    );
+    writeln!(
+        out,
+        "        let content = serde_json::to_vec_pretty(self).map_err(FixtureError::Json)?;"
+    );
+    writeln!(
+        out,
+        "        std::fs::write(path, content).map_err(FixtureError::Io)"
+    );
+    writeln!(out, "    }}");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    print_doc(
+        out,
+        "Decodes `request` into its id, method and params as serialized by the request type of \
+        the method.",
+        0,
+    );
+    writeln!(out, "fn normalize_request(");
+    writeln!(out, "    request: &serde_json::Value,");
+    writeln!(
+        out,
+        ") -> Result<(Option<JsonRpcId>, JsonRpcMethod, serde_json::Value), serde_json::Error> {{"
+    );
+    writeln!(
+        out,
+        "    let request = JsonRpcRequest::deserialize(request)?;"
+    );
+    writeln!(out, "    let mut value = serde_json::to_value(&request)?;");
+    writeln!(out);
+    writeln!(
+        out,
+        "    Ok((request.id, request.data.method(), value[\"params\"].take()))"
+    );
+    writeln!(out, "}}");
+    writeln!(out);
+
+    writeln!(out, "impl<T> RecordingTransport<T> {{");
+    writeln!(
+        out,
+        "    /// Records exchanges through `inner` to a new fixture at `path`."
+    );
+    writeln!(
+        out,
+        "    pub fn new(inner: T, path: impl Into<std::path::PathBuf>) -> Self {{"
+    );
+    writeln!(out, "        Self {{");
+    writeln!(out, "            inner,");
+    writeln!(out, "            path: path.into(),");
+    writeln!(out, "            fixture: Default::default(),");
+    writeln!(out, "        }}");
+    writeln!(out, "    }}");
+    writeln!(out);
+    writeln!(out, "    pub fn fixture(&self) -> JsonRpcFixture {{");
+    writeln!(out, "        self.fixture");
+    writeln!(out, "            .lock()");
+    writeln!(
+        out,
+        "            .unwrap_or_else(std::sync::PoisonError::into_inner)"
+    );
+    writeln!(out, "            .clone()");
+    writeln!(out, "    }}");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    writeln!(
+        out,
+        "impl<T: JsonRpcTransport + Sync> JsonRpcTransport for RecordingTransport<T> {{"
+    );
+    writeln!(out, "    type Error = RecordingError<T::Error>;");
+    writeln!(out);
+    writeln!(out, "    fn send(");
+    writeln!(out, "        &self,");
+    writeln!(out, "        request: serde_json::Value,");
+    writeln!(
+        out,
+        "    ) -> impl std::future::Future<Output = Result<serde_json::Value, Self::Error>> + Send {{"
+    );
+    writeln!(out, "        async move {{");
+    writeln!(
+        out,
+        "            let json_error = |err| RecordingError::Fixture(FixtureError::Json(err));"
+    );
+    writeln!(out);
+    writeln!(
+        out,
+        "            let (_, method, params) = normalize_request(&request).map_err(json_error)?;"
+    );
+    writeln!(out, "            let response = self");
+    writeln!(out, "                .inner");
+    writeln!(out, "                .send(request)");
+    writeln!(out, "                .await");
+    writeln!(out, "                .map_err(RecordingError::Transport)?;");
+    writeln!(out);
+    writeln!(
+        out,
+        "            let recorded = match serde_json::from_value::<JsonRpcResponse>(response.clone())"
+    );
+    writeln!(out, "                .map_err(json_error)?");
+    writeln!(out, "            {{");
+    writeln!(
+        out,
+        "                JsonRpcResponse::Success {{ result, .. }} => {{"
+    );
+    writeln!(
+        out,
+        "                    method.decode_result(result.clone()).map_err(json_error)?;"
+    );
+    writeln!(out, "                    RecordedResponse::Result(result)");
+    writeln!(out, "                }}");
+    writeln!(
+        out,
+        "                JsonRpcResponse::Error {{ error, .. }} => RecordedResponse::Error(error),"
+    );
+    writeln!(out, "            }};");
+    writeln!(out);
+    writeln!(out, "            let mut fixture = self");
+    writeln!(out, "                .fixture");
+    writeln!(out, "                .lock()");
+    writeln!(
+        out,
+        "                .unwrap_or_else(std::sync::PoisonError::into_inner);"
+    );
+    writeln!(
+        out,
+        "            fixture.exchanges.push(RecordedExchange {{"
+    );
+    writeln!(out, "                method,");
+    writeln!(out, "                params,");
+    writeln!(out, "                response: recorded,");
+    writeln!(out, "            }});");
+    writeln!(
+        out,
+        "            fixture.save(&self.path).map_err(RecordingError::Fixture)?;"
+    );
+    writeln!(out);
+    writeln!(out, "            Ok(response)");
+    writeln!(out, "        }}");
+    writeln!(out, "    }}");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    writeln!(out, "impl ReplayTransport {{");
+    writeln!(out, "    pub fn new(fixture: JsonRpcFixture) -> Self {{");
+    writeln!(out, "        Self {{");
+    writeln!(
+        out,
+        "            exchanges: std::sync::Mutex::new(fixture.exchanges),"
+    );
+    writeln!(out, "        }}");
+    writeln!(out, "    }}");
+    writeln!(out);
+    writeln!(out, "    /// Replays the fixture at `path`.");
+    writeln!(
+        out,
+        "    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, FixtureError> {{"
+    );
+    writeln!(out, "        Ok(Self::new(JsonRpcFixture::load(path)?))");
+    writeln!(out, "    }}");
+    writeln!(out);
+    writeln!(
+        out,
+        "    fn respond(&self, request: serde_json::Value) -> Result<serde_json::Value, FixtureError> {{"
+    );
+    writeln!(out, "        let (id, method, params) = normalize_request(&request).map_err(FixtureError::Json)?;");
+    writeln!(
+        out,
+        "        // Transports always answer, so notifications get the `null` id"
+    );
+    writeln!(out, "        let id = id.unwrap_or(JsonRpcId::Null);");
+    writeln!(out, "        let mut exchanges = self");
+    writeln!(out, "            .exchanges");
+    writeln!(out, "            .lock()");
+    writeln!(
+        out,
+        "            .unwrap_or_else(std::sync::PoisonError::into_inner);"
+    );
+    writeln!(out, "        let position = exchanges");
+    writeln!(out, "            .iter()");
+    writeln!(
+        out,
+        "            .position(|exchange| exchange.method == method && exchange.params == params)"
+    );
+    writeln!(
+        out,
+        "            .ok_or(FixtureError::NoRecordedExchange(method))?;"
+    );
+    writeln!(out);
+    writeln!(
+        out,
+        "        let response = match exchanges.remove(position).response {{"
+    );
+    writeln!(out, "            RecordedResponse::Result(result) => JsonRpcResponse::Success {{ id, result }},");
+    writeln!(
+        out,
+        "            RecordedResponse::Error(error) => JsonRpcResponse::Error {{ id, error }},"
+    );
+    writeln!(out, "        }};");
+    writeln!(
+        out,
+        "        serde_json::to_value(response).map_err(FixtureError::Json)"
+    );
+    writeln!(out, "    }}");
+    writeln!(out, "}}");
+    writeln!(out);
+
+    writeln!(out, "impl JsonRpcTransport for ReplayTransport {{");
+    writeln!(out, "    type Error = FixtureError;");
+    writeln!(out);
+    writeln!(out, "    fn send(");
+    writeln!(out, "        &self,");
+    writeln!(out, "        request: serde_json::Value,");
+    writeln!(
+        out,
+        "    ) -> impl std::future::Future<Output = Result<serde_json::Value, Self::Error>> + Send {{"
+    );
+    writeln!(out, "        std::future::ready(self.respond(request))");
+    writeln!(out, "    }}");
+    writeln!(out, "}}");
+}
+
+/// Detection of the spec version implemented by a node, picking between the clients generated for
+/// several spec versions.
+#[derive(Debug, Clone)]
+pub struct VersionNegotiation {
+    /// Versions in the order they're checked, newest first
+    pub versions: Vec<NegotiatedVersion>,
+    /// Read methods not available in all versions, probed when `starknet_specVersion` isn't
+    /// implemented. Write methods are never probed as they could submit transactions.
+    pub probed_methods: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NegotiatedVersion {
+    /// Module the version is generated into, e.g. `v0_3`
+    pub module: &'static str,
+    /// Variant name of the version in `VersionedClient`, e.g. `V0_3`
+    pub variant_name: String,
+    /// Which of the probed methods the version has, or `None` if that can't tell it apart from a
+    /// newer version, which is picked instead
+    pub available_methods: Option<Vec<bool>>,
+}
+
+impl VersionNegotiation {
+    /// Resolves the negotiation between versions given as module names and specifications, with
+    /// the names of the write methods of all versions.
+    pub fn resolve(versions: &[(&'static str, &Specification)], write_methods: &[String]) -> Self {
+        let method_names = |specs: &Specification| {
+            specs
+                .methods
+                .iter()
+                .map(|method| method.name.clone())
+                .filter(|name| !write_methods.contains(name))
+                .collect::<Vec<_>>()
+        };
+
+        let mut probed_methods = versions
+            .iter()
+            .flat_map(|(_, specs)| method_names(specs))
+            .filter(|name| {
+                !versions
+                    .iter()
+                    .all(|(_, specs)| method_names(specs).contains(name))
+            })
+            .collect::<Vec<_>>();
+        probed_methods.sort();
+        probed_methods.dedup();
+
+        let mut seen = vec![];
+        let versions = versions
+            .iter()
+            .rev()
+            .map(|(module, specs)| {
+                let methods = method_names(specs);
+                let available = probed_methods
+                    .iter()
+                    .map(|name| methods.contains(name))
+                    .collect::<Vec<_>>();
+
+                let available_methods = if seen.contains(&available) {
+                    None
+                } else {
+                    seen.push(available.clone());
+                    Some(available)
+                };
+
+                NegotiatedVersion {
+                    module,
+                    variant_name: module.to_uppercase(),
+                    available_methods,
+                }
+            })
+            .collect();
+
+        Self {
+            versions,
+            probed_methods,
+        }
+    }
+
+    pub fn render_stdout(&self, out: &mut CodeWriter) {
+        let undetectable = self
+            .versions
+            .iter()
+            .filter(|version| version.available_methods.is_none())
+            .collect::<Vec<_>>();
+        if !undetectable.is_empty() {
+            writeln!(
+                out,
+                "// These versions have the same read methods as a newer version, so they're only detected"
+            );
+            writeln!(
+                out,
+                "// through `starknet_specVersion` and the newer version is picked otherwise:"
+            );
+            for version in undetectable {
+                writeln!(out, "// - `{}`", version.module);
+            }
+            writeln!(out);
+        }
+
+        writeln!(
+            out,
+            "use super::{{shared::JsonRpcTransport, {}}};",
+            self.versions
+                .iter()
+                .rev()
+                .map(|version| version.module)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        writeln!(out);
+        print_doc(
+            out,
+            "JSON-RPC error code of requests to methods a node doesn't implement.",
+            0,
+        );
+        writeln!(out, "const METHOD_NOT_FOUND: i64 = -32601;");
+        writeln!(out);
+        print_doc(
+            out,
+            "Read methods only available in some spec versions, probed to detect the version of \
+            nodes not implementing `starknet_specVersion`. Write methods are never probed as they \
+            could submit transactions.",
+            0,
+        );
+        if self.probed_methods.is_empty() {
+            writeln!(out, "const PROBED_METHODS: [&str; 0] = [];");
+        } else {
+            writeln!(
+                out,
+                "const PROBED_METHODS: [&str; {}] = [",
+                self.probed_methods.len()
+            );
+            for method in self.probed_methods.iter() {
+                writeln!(out, "    \"{method}\",");
+            }
+            writeln!(out, "];");
+        }
+        writeln!(out);
+
+        print_doc(out, "Client for the spec version implemented by a node.", 0);
+        writeln!(out, "#[derive(Debug)]");
+        writeln!(out, "pub enum VersionedClient<T> {{");
+        for version in self.versions.iter() {
+            writeln!(
+                out,
+                "    {}({}::JsonRpcClient<T>),",
+                version.variant_name, version.module
+            );
+        }
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(
+            out,
+            "Error detecting the spec version implemented by a node.",
+            0,
+        );
+        writeln!(out, "#[derive(Debug, thiserror::Error)]");
+        writeln!(out, "pub enum VersionNegotiationError<T> {{");
+        writeln!(out, "    /// The transport failed to send a request");
+        writeln!(out, "    #[error(transparent)]");
+        writeln!(out, "    Transport(T),");
+        writeln!(
+            out,
+            "    /// The node reported a spec version no client is generated for"
+        );
+        writeln!(out, "    #[error(\"unsupported spec version `{{0}}`\")]");
+        writeln!(out, "    UnsupportedVersion(String),");
+        writeln!(
+            out,
+            "    /// The methods implemented by the node don't match any spec version"
+        );
+        writeln!(
+            out,
+            "    #[error(\"unable to detect the spec version implemented by the node\")]"
+        );
+        writeln!(out, "    UnknownVersion,");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        print_doc(out, "Response to a request sent while probing a node.", 0);
+        writeln!(out, "enum ProbeResponse {{");
+        writeln!(out, "    Result(serde_json::Value),");
+        writeln!(out, "    MethodNotFound,");
+        writeln!(out, "    Error,");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        self.render_client_impl_stdout(out);
+        writeln!(out);
+
+        print_doc(out, "Sends a request to `method` without params.", 0);
+        writeln!(out, "async fn probe<T: JsonRpcTransport>(");
+        writeln!(out, "    transport: &T,");
+        writeln!(out, "    method: &str,");
+        writeln!(
+            out,
+            ") -> Result<ProbeResponse, VersionNegotiationError<T::Error>> {{"
+        );
+        writeln!(out, "    let response = transport");
+        writeln!(out, "        .send(serde_json::json!({{");
+        writeln!(out, "            \"jsonrpc\": \"2.0\",");
+        writeln!(out, "            \"id\": 0,");
+        writeln!(out, "            \"method\": method,");
+        writeln!(out, "            \"params\": [],");
+        writeln!(out, "        }}))");
+        writeln!(out, "        .await");
+        writeln!(
+            out,
+            "        .map_err(VersionNegotiationError::Transport)?;"
+        );
+        writeln!(out);
+        writeln!(
+            out,
+            "    Ok(match (response.get(\"result\"), response.get(\"error\")) {{"
+        );
+        writeln!(
+            out,
+            "        (Some(result), _) => ProbeResponse::Result(result.clone()),"
+        );
+        writeln!(out, "        (None, Some(error))");
+        writeln!(
+            out,
+            "            if error.get(\"code\").and_then(serde_json::Value::as_i64) == Some(METHOD_NOT_FOUND) =>"
+        );
+        writeln!(out, "        {{");
+        writeln!(out, "            ProbeResponse::MethodNotFound");
+        writeln!(out, "        }}");
+        writeln!(out, "        _ => ProbeResponse::Error,");
+        writeln!(out, "    }})");
+        writeln!(out, "}}");
+    }
+
+    fn render_client_impl_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "impl<T> VersionedClient<T> {{");
+        print_doc(
+            out,
+            "Creates the client for `version` as reported by `starknet_specVersion`, only \
+            comparing major and minor versions.",
+            4,
+        );
+        writeln!(
+            out,
+            "    pub fn for_spec_version(version: &str, transport: T) -> Option<Self> {{"
+        );
+        writeln!(out, "        fn major_minor(version: &str) -> Vec<&str> {{");
+        writeln!(out, "            version.split('.').take(2).collect()");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        let version = major_minor(version);");
+        for (ind, version) in self.versions.iter().enumerate() {
+            writeln!(
+                out,
+                "        {}if version == major_minor({}::SPEC_VERSION) {{",
+                if ind == 0 { "" } else { "} else " },
+                version.module
+            );
+            writeln!(
+                out,
+                "            Some(Self::{}({}::JsonRpcClient::new(transport)))",
+                version.variant_name, version.module
+            );
+        }
+        writeln!(out, "        }} else {{");
+        writeln!(out, "            None");
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "    /// Version of the specification implemented by the client."
+        );
+        writeln!(out, "    pub fn spec_version(&self) -> &'static str {{");
+        writeln!(out, "        match self {{");
+        for version in self.versions.iter() {
+            writeln!(
+                out,
+                "            Self::{}(_) => {}::SPEC_VERSION,",
+                version.variant_name, version.module
+            );
+        }
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl<T: JsonRpcTransport> VersionedClient<T> {{");
+        print_doc(
+            out,
+            "Creates the client for the spec version implemented by the node behind `transport`. \
+            The version reported by `starknet_specVersion` is used when the node implements it, \
+            and is otherwise detected from the read methods the node implements, picking the newest \
+            of the versions they can't tell apart.",
+            4,
+        );
+        writeln!(
+            out,
+            "    pub async fn negotiate(transport: T) -> Result<Self, VersionNegotiationError<T::Error>> {{"
+        );
+        writeln!(
+            out,
+            "        if let ProbeResponse::Result(serde_json::Value::String(version)) ="
+        );
+        writeln!(
+            out,
+            "            probe(&transport, \"starknet_specVersion\").await?"
+        );
+        writeln!(out, "        {{");
+        writeln!(
+            out,
+            "            return Self::for_spec_version(&version, transport)"
+        );
+        writeln!(
+            out,
+            "                .ok_or(VersionNegotiationError::UnsupportedVersion(version));"
+        );
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "        let mut available = [false; {}];",
+            self.probed_methods.len()
+        );
+        writeln!(
+            out,
+            "        for (ind, method) in PROBED_METHODS.iter().enumerate() {{"
+        );
+        writeln!(
+            out,
+            "            available[ind] = !matches!(probe(&transport, method).await?, ProbeResponse::MethodNotFound);"
+        );
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        match available {{");
+        for version in self.versions.iter() {
+            if let Some(available_methods) = &version.available_methods {
+                writeln!(
+                    out,
+                    "            [{}] => Ok(Self::{}({}::JsonRpcClient::new(transport))),",
+                    available_methods
+                        .iter()
+                        .map(|item| item.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    version.variant_name,
+                    version.module
+                );
+            }
+        }
+        // The fallback arm is unreachable once versions cover all combinations of probed methods,
+        // which can't happen when there are too many to count
+        let detected = self
+            .versions
+            .iter()
+            .filter(|version| version.available_methods.is_some())
+            .count();
+        let combinations = u32::try_from(self.probed_methods.len())
+            .ok()
+            .and_then(|len| 1usize.checked_shl(len));
+        if combinations.is_none_or(|combinations| detected < combinations) {
+            writeln!(
+                out,
+                "            _ => Err(VersionNegotiationError::UnknownVersion),"
+            );
+        }
+        writeln!(out, "        }}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+}
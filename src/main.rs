@@ -1,11 +1,15 @@
-use std::{collections::HashSet, str::FromStr};
+use std::{collections::HashSet, str::FromStr, sync::LazyLock};
 
 use anyhow::Result;
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
+use indexmap::IndexMap;
 use regex::Regex;
 
-use crate::spec::*;
+use crate::{conversions::*, graph::*, jsonrpc::*, spec::*};
 
+mod conversions;
+mod graph;
+mod jsonrpc;
 mod spec;
 
 mod built_info {
@@ -14,11 +18,202 @@ mod built_info {
 
 const MAX_LINE_LENGTH: usize = 100;
 
+/// Generated code of a module, collected so that it's indented once when printed into its
+/// `pub mod` block.
+struct CodeWriter {
+    code: String,
+    /// Indentation of the module the code is printed into, which docs are wrapped to fit in.
+    indent: usize,
+}
+
 #[derive(Debug, Parser)]
 #[clap(author, version, about)]
 struct Cli {
-    #[clap(long, env, help = "Version of the specification")]
-    spec: SpecVersion,
+    #[clap(
+        long,
+        env,
+        value_delimiter = ',',
+        required_unless_present = "all_specs",
+        help = "Versions of the specification, each generated into its own module when more than one is selected"
+    )]
+    spec: Vec<SpecVersion>,
+    #[clap(
+        long,
+        conflicts_with = "spec",
+        help = "Generate all versions of the specification, each into its own module"
+    )]
+    all_specs: bool,
+    #[clap(
+        long,
+        value_name = "PATH",
+        help = "OpenRPC document, including write methods, to generate code from instead of the one shipped for the selected version"
+    )]
+    spec_file: Option<std::path::PathBuf>,
+    #[clap(
+        long,
+        help = "Generate `arbitrary::Arbitrary` implementations for all types"
+    )]
+    arbitrary: bool,
+    #[clap(
+        long,
+        value_delimiter = ',',
+        help = "Extra traits to derive for all generated types"
+    )]
+    derive: Vec<String>,
+    #[clap(
+        long,
+        value_delimiter = ',',
+        help = "Default traits (e.g. `Copy` for enums) not to derive for any generated type"
+    )]
+    no_derive: Vec<String>,
+    #[clap(
+        long,
+        help = "Extra attribute (e.g. `#[non_exhaustive]`) for all generated types"
+    )]
+    attribute: Vec<String>,
+    #[clap(
+        long,
+        value_name = "TYPE=TRAITS",
+        value_parser = parse_type_option,
+        help = "Extra comma-separated traits to derive for a single generated type"
+    )]
+    type_derive: Vec<(String, String)>,
+    #[clap(
+        long,
+        value_name = "TYPE=TRAITS",
+        value_parser = parse_type_option,
+        help = "Default comma-separated traits not to derive for a single generated type"
+    )]
+    type_no_derive: Vec<(String, String)>,
+    #[clap(
+        long,
+        value_name = "TYPE=VARIANT",
+        value_parser = parse_type_option,
+        help = "Variant of an enum marked `#[default]`, so that the enum can derive `Default`"
+    )]
+    default_variant: Vec<(String, String)>,
+    #[clap(
+        long,
+        value_name = "TYPE=ATTRIBUTE",
+        value_parser = parse_type_option,
+        help = "Extra attribute for a single generated type"
+    )]
+    type_attribute: Vec<(String, String)>,
+    #[clap(long, help = "Generate builders for all structs")]
+    builders: bool,
+    #[clap(
+        long,
+        value_name = "TYPE",
+        help = "Generate a borrowed `Ref` variant for a model type"
+    )]
+    ref_type: Vec<String>,
+    #[clap(
+        long,
+        value_name = "TYPE",
+        help = "Generate a zero-copy `Borrowed` variant for a model type and the structs it contains"
+    )]
+    borrowed_type: Vec<String>,
+    #[clap(long, help = "How `None` values of optional fields are serialized")]
+    none_serialization: Option<NoneSerialization>,
+    #[clap(
+        long,
+        value_name = "TYPE.FIELD=POLICY",
+        value_parser = parse_type_option,
+        help = "How `None` values of a single optional field are serialized"
+    )]
+    field_none_serialization: Vec<(String, String)>,
+    #[clap(long, help = "Add `#[serde(default)]` to all optional fields")]
+    serde_default: bool,
+    #[clap(
+        long,
+        value_name = "TYPE.FIELD=BOOL",
+        value_parser = parse_type_option,
+        help = "Whether to add `#[serde(default)]` to a single optional field"
+    )]
+    field_serde_default: Vec<(String, String)>,
+    #[clap(
+        long,
+        help = "Generate accessor traits for the properties of flattened schemas"
+    )]
+    accessor_traits: bool,
+    #[clap(
+        long,
+        help = "Generate enums for `oneOf` schemas, with conversions from and to their variants"
+    )]
+    oneof_enums: bool,
+    #[clap(
+        long,
+        help = "Generate response types for methods with inline result schemas"
+    )]
+    response_types: bool,
+    #[clap(
+        long,
+        value_delimiter = ',',
+        help = "Only generate types used by these methods"
+    )]
+    methods: Vec<String>,
+    #[clap(
+        long,
+        requires = "response_types",
+        help = "Generate JSON-RPC 2.0 request, response and batch envelope types for all methods"
+    )]
+    jsonrpc_envelopes: bool,
+    #[clap(
+        long,
+        requires = "jsonrpc_envelopes",
+        help = "Generate a JSON-RPC client for all methods over a pluggable transport"
+    )]
+    client: bool,
+    #[clap(
+        long,
+        requires = "client",
+        help = "Generate a `MockProvider` transport with per-method expectations for tests"
+    )]
+    mock_provider: bool,
+    #[clap(
+        long,
+        requires = "client",
+        help = "Generate transports recording exchanges to JSON fixtures and replaying them"
+    )]
+    record_replay: bool,
+    #[clap(
+        long,
+        help = "Generate conversions between the same-named types of adjacent spec versions, when generating several"
+    )]
+    version_conversions: bool,
+    #[clap(
+        long,
+        requires = "client",
+        help = "Generate `SPEC_VERSION` constants, and a client detecting the spec version of a node when generating several"
+    )]
+    version_negotiation: bool,
+    #[clap(
+        long,
+        help = "Report which `allOf` fragments are flattened when inferred by the generation profile"
+    )]
+    report_flatten: bool,
+    #[clap(
+        long,
+        help = "Report fixed fields inferred from the spec, and profile entries made redundant by them"
+    )]
+    report_fixed_fields: bool,
+    #[clap(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Export the reference graph of the specification instead of generating code
+    Graph {
+        #[clap(long, default_value = "dot", help = "Output format")]
+        format: GraphFormat,
+        #[clap(
+            long,
+            value_name = "NAME",
+            help = "Only export the schema, method or error and everything depending on it"
+        )]
+        dependents_of: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -27,8 +222,18 @@ struct GenerationProfile {
     raw_specs: RawSpecs,
     flatten_options: FlattenOption,
     ignore_types: Vec<String>,
+    /// Fixed fields are inferred from single-value enums and consts, so only the ones the spec
+    /// leaves unconstrained are listed. We need these because they're implied by the network but
+    /// not explicitly specified. So it's impossible to dynamically derive them accurately.
     fixed_field_types: FixedFieldsOptions,
     arc_wrapped_types: ArcWrappingOptions,
+    derive_options: DeriveOptions,
+    /// Model types to generate borrowed `Ref` variants for, in addition to request types
+    ref_types: Vec<String>,
+    /// Model types to generate zero-copy `Borrowed` deserialization variants for. Structs
+    /// contained in these types get `Borrowed` variants too.
+    borrowed_types: Vec<String>,
+    optional_field_options: OptionalFieldOptions,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -54,6 +259,44 @@ struct ArcWrappingOptions {
     arc_wrapped_types: Vec<RustTypeWithArcWrappedFields>,
 }
 
+#[derive(Debug, Clone)]
+struct DeriveOptions {
+    /// Traits derived for all generated types on top of the default ones. `Default` is only
+    /// derived for the types that can derive it.
+    derives: Vec<String>,
+    /// Default traits not derived for any generated type
+    removed_derives: Vec<String>,
+    /// Attributes added to all generated types
+    attributes: Vec<String>,
+    types: Vec<RustTypeWithDerives>,
+}
+
+#[derive(Debug, Clone)]
+struct OptionalFieldOptions {
+    /// How `None` is serialized for optional fields without an override
+    none_serialization: NoneSerialization,
+    /// Whether optional fields without an override get `#[serde(default)]`
+    serde_default: bool,
+    fields: Vec<OptionalFieldOverride>,
+}
+
+/// How `None` values of optional fields are serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NoneSerialization {
+    /// The field is omitted with `skip_serializing_if`
+    Omit,
+    /// The field is serialized as `null`
+    Null,
+}
+
+#[derive(Debug, Clone)]
+struct OptionalFieldOverride {
+    type_name: String,
+    field_name: String,
+    none_serialization: Option<NoneSerialization>,
+    serde_default: Option<bool>,
+}
+
 #[derive(Debug, Clone)]
 struct RustTypeWithFixedFields {
     name: &'static str,
@@ -66,6 +309,16 @@ struct RustTypeWithArcWrappedFields {
     fields: Vec<&'static str>,
 }
 
+#[derive(Debug, Clone)]
+struct RustTypeWithDerives {
+    name: String,
+    derives: Vec<String>,
+    removed_derives: Vec<String>,
+    attributes: Vec<String>,
+    /// Variant marked `#[default]`, for enums
+    default_variant: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 struct FixedField {
     name: &'static str,
@@ -77,62 +330,159 @@ struct TypeResolutionResult {
     model_types: Vec<RustType>,
     request_response_types: Vec<RustType>,
     not_implemented: Vec<String>,
+    /// Fixed fields inferred from the spec, reported with `--report-fixed-fields`
+    fixed_field_report: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+/// A profile with its specification and generated types resolved, ready to be printed.
+struct ResolvedProfile {
+    profile: GenerationProfile,
+    specs: Specification,
+    result: TypeResolutionResult,
+}
+
+/// Module generated code is printed into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputModule {
+    /// The only module, when generating a single spec version
+    Single,
+    /// Module with the types generated identically for all spec versions
+    Shared,
+    /// Module of a single spec version, re-exporting the shared types
+    Versioned,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 struct RustType {
     title: Option<String>,
     description: Option<String>,
     name: String,
+    extra_derives: Vec<String>,
+    /// Default or extra derives left out
+    removed_derives: Vec<String>,
+    extra_attributes: Vec<String>,
     content: RustTypeKind,
 }
 
 #[allow(unused)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 enum RustTypeKind {
     Struct(RustStruct),
     Enum(RustEnum),
     Wrapper(RustWrapper),
     Unit(RustUnit),
+    OneOf(RustOneOf),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct RustStruct {
-    serde_as_array: bool,
+    /// `paramStructure` of the method, for request params. Params are serialized as an object
+    /// keyed by field names if passed by name and as an array otherwise, and both forms are
+    /// accepted when deserializing.
+    params: Option<ParamStructure>,
     extra_ref_type: bool,
+    extra_borrowed_type: bool,
     fields: Vec<RustField>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct RustEnum {
     thiserror: bool,
+    /// Name of the variant marked `#[default]`, if any
+    default_variant: Option<String>,
     variants: Vec<RustVariant>,
 }
 
+/// An untagged enum generated from a `oneOf` schema, with one newtype variant per referenced
+/// schema.
+#[derive(Debug, Clone, PartialEq)]
+struct RustOneOf {
+    extra_ref_type: bool,
+    extra_borrowed_type: bool,
+    variants: Vec<RustOneOfVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct RustOneOfVariant {
+    description: Option<String>,
+    name: String,
+    type_name: String,
+    /// The only value accepted by a `bool` variant, which is then generated as a unit variant
+    literal: Option<bool>,
+    /// The only value a `bool` variant that isn't a literal is documented to accept, which
+    /// arbitrary values stick to
+    legal_value: Option<bool>,
+    /// Type of the variant in the `Ref` variant of the enum, if any
+    ref_type_name: Option<String>,
+    /// Type of the variant in the `Borrowed` variant of the enum, if any
+    borrowed_type_name: Option<String>,
+}
+
+/// A conversion between a `oneOf` enum and a type nested inside it, possibly through other `oneOf`
+/// enums.
 #[derive(Debug, Clone)]
+struct OneOfConversion {
+    enum_name: String,
+    type_name: String,
+    /// Enum and variant names from the outermost enum to the one directly holding the type
+    path: Vec<(String, String)>,
+    /// Whether all enums on the path have a single variant, making the conversion infallible
+    exhaustive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 struct RustWrapper {
     type_name: String,
+    serializer: Option<SerializerOverride>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct RustUnit {
-    serde_as_array: bool,
+    /// `paramStructure` of the method, for request params, serialized as an empty object if
+    /// passed by name and as an empty array otherwise
+    params: Option<ParamStructure>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct RustField {
     description: Option<String>,
     name: String,
     optional: bool,
-    fixed: Option<FixedField>,
+    /// Expression of the value the field is fixed to, which is then left out of the struct
+    fixed: Option<String>,
     arc_wrap: bool,
     type_name: String,
     serde_rename: Option<String>,
     serde_faltten: bool,
     serializer: Option<SerializerOverride>,
+    /// Whether the field type has a `Ref` variant to be used in the parent's `Ref` variant
+    nested_ref: bool,
+    /// Type of the field in the parent's `Borrowed` variant, if any
+    borrowed_type_name: Option<String>,
+    /// Whether the field can be `null`. Fields that are both optional and nullable use
+    /// `Option<Option<T>>` to tell absent and `null` values apart.
+    nullable: bool,
+    /// Whether `None` is serialized as `null` instead of omitting optional fields
+    serialize_none: bool,
+    /// Whether optional fields get `#[serde(default)]`
+    serde_default: bool,
+    /// Names of the flattened schemas the field was copied from, innermost first
+    flattened_from: Vec<String>,
 }
 
+/// A trait with accessors for the properties of a flattened schema, implemented by all structs
+/// the schema was flattened into.
 #[derive(Debug, Clone)]
+struct AccessorTrait {
+    name: String,
+    schema_name: String,
+    fields: Vec<RustField>,
+    implementors: Vec<String>,
+    /// `oneOf` enums whose variants all implement the trait, with their variant names
+    delegating_implementors: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 struct RustVariant {
     description: Option<String>,
     name: String,
@@ -146,17 +496,132 @@ struct RustFieldType {
     serializer: Option<SerializerOverride>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 enum SerializerOverride {
     Serde(String),
     SerdeAs(String),
 }
 
+/// A `#[serde(with)]` module that can be used inside `serde_as` wrappers like `Vec<_>` and
+/// `Option<_>` through a generated adapter type.
+struct SerdeWithAdapter {
+    module: &'static str,
+    adapter: &'static str,
+    type_name: &'static str,
+}
+
+const SERDE_WITH_ADAPTERS: [SerdeWithAdapter; 1] = [SerdeWithAdapter {
+    module: "base64",
+    adapter: "Base64As",
+    type_name: "Vec<u8>",
+}];
+
+/// Matches the identifiers in a Rust type name, like `Vec` and `FieldElement` in
+/// `Vec<FieldElement>`.
+static IDENTIFIER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").unwrap());
+
 #[allow(unused)]
 #[derive(Debug, Clone)]
 enum FlattenOption {
     All,
     Selected(Vec<String>),
+    /// Flattens schemas that are only ever used as `allOf` fragments, with overrides for
+    /// schemas to always or never flatten.
+    Inferred {
+        /// Fragments also used as standalone types, flattened anyway to match `starknet-rs`
+        flatten: Vec<String>,
+        /// Fragments kept as nested `#[serde(flatten)]` fields, like the event filter
+        no_flatten: Vec<String>,
+    },
+}
+
+impl FlattenOption {
+    fn should_flatten(&self, schema_name: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Selected(flatten_types) => flatten_types.iter().any(|item| item == schema_name),
+            Self::Inferred { .. } => {
+                unreachable!("inferred flatten options must be resolved first")
+            }
+        }
+    }
+
+    /// Turns inferred options into the list of schemas selected for flattening, reporting the
+    /// decisions made if `report` is set.
+    fn resolve(&self, specs: &Specification, report: bool) -> Self {
+        let (flatten, no_flatten) = match self {
+            Self::Inferred {
+                flatten,
+                no_flatten,
+            } => (flatten, no_flatten),
+            _ => return self.clone(),
+        };
+
+        let mut fragments = specs
+            .components
+            .schemas
+            .values()
+            .chain(get_method_and_error_schemas(specs))
+            .flat_map(get_all_of_fragments)
+            .collect::<Vec<_>>();
+        fragments.sort();
+        fragments.dedup();
+
+        let flatten_only = get_flatten_only_schemas(specs, &Self::All);
+
+        let mut selected = vec![];
+        for fragment in fragments.into_iter() {
+            let (should_flatten, reason) = if flatten.contains(&fragment) {
+                (true, "profile override")
+            } else if no_flatten.contains(&fragment) {
+                (false, "profile override")
+            } else if flatten_only.contains(&fragment) {
+                (true, "only used as `allOf` fragment")
+            } else {
+                (false, "also used as standalone type")
+            };
+
+            if report {
+                eprintln!(
+                    "{} `{}`: {}",
+                    if should_flatten {
+                        "Flattening"
+                    } else {
+                        "Not flattening"
+                    },
+                    fragment,
+                    reason
+                );
+            }
+
+            if should_flatten {
+                selected.push(fragment);
+            }
+        }
+
+        Self::Selected(selected)
+    }
+}
+
+impl SpecVersion {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::V0_1_0 => "0.1.0",
+            Self::V0_2_1 => "0.2.1",
+            Self::V0_3_0 => "0.3.0",
+        }
+    }
+
+    /// Name of the module code for this version is generated into when generating several
+    /// versions at once.
+    fn module_name(&self) -> &'static str {
+        match self {
+            Self::V0_1_0 => "v0_1",
+            Self::V0_2_1 => "v0_2",
+            Self::V0_3_0 => "v0_3",
+        }
+    }
 }
 
 impl FromStr for SpecVersion {
@@ -177,25 +642,68 @@ impl ValueEnum for SpecVersion {
         &[Self::V0_1_0, Self::V0_2_1, Self::V0_3_0]
     }
 
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        use clap::builder::PossibleValue;
+
+        Some(PossibleValue::new(self.as_str()))
+    }
+}
+
+impl FromStr for NoneSerialization {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "omit" => Self::Omit,
+            "null" => Self::Null,
+            _ => anyhow::bail!("unknown `None` serialization: {}", s),
+        })
+    }
+}
+
+impl ValueEnum for NoneSerialization {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Omit, Self::Null]
+    }
+
     fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
         use clap::builder::PossibleValue;
 
         match self {
-            Self::V0_1_0 => Some(PossibleValue::new("0.1.0")),
-            Self::V0_2_1 => Some(PossibleValue::new("0.2.1")),
-            Self::V0_3_0 => Some(PossibleValue::new("0.3.0")),
+            Self::Omit => Some(PossibleValue::new("omit")),
+            Self::Null => Some(PossibleValue::new("null")),
         }
     }
 }
 
+impl OptionalFieldOptions {
+    fn none_serialization_for(&self, type_name: &str, field_name: &str) -> NoneSerialization {
+        self.fields
+            .iter()
+            .rev()
+            .filter(|item| item.type_name == type_name && item.field_name == field_name)
+            .find_map(|item| item.none_serialization)
+            .unwrap_or(self.none_serialization)
+    }
+
+    fn serde_default_for(&self, type_name: &str, field_name: &str) -> bool {
+        self.fields
+            .iter()
+            .rev()
+            .filter(|item| item.type_name == type_name && item.field_name == field_name)
+            .find_map(|item| item.serde_default)
+            .unwrap_or(self.serde_default)
+    }
+}
+
 impl FixedFieldsOptions {
-    fn find_fixed_field(&self, type_name: &str, field_name: &str) -> Option<FixedField> {
+    fn find_fixed_field(&self, type_name: &str, field_name: &str) -> Option<&'static str> {
         self.fixed_field_types.iter().find_map(|item| {
             if item.name == type_name {
                 item.fields
                     .iter()
                     .find(|field| field.name == field_name)
-                    .cloned()
+                    .map(|field| field.value)
             } else {
                 None
             }
@@ -215,57 +723,278 @@ impl ArcWrappingOptions {
     }
 }
 
+impl DeriveOptions {
+    fn derives_for(&self, type_name: &str) -> Vec<String> {
+        let mut derives = self.derives.clone();
+        for item in self.types.iter().filter(|item| item.name == type_name) {
+            derives.extend(item.derives.iter().cloned());
+        }
+        derives
+    }
+
+    fn removed_derives_for(&self, type_name: &str) -> Vec<String> {
+        let mut removed_derives = self.removed_derives.clone();
+        for item in self.types.iter().filter(|item| item.name == type_name) {
+            removed_derives.extend(item.removed_derives.iter().cloned());
+        }
+        removed_derives
+    }
+
+    fn default_variant_for(&self, type_name: &str) -> Option<&str> {
+        self.types
+            .iter()
+            .rev()
+            .filter(|item| item.name == type_name)
+            .find_map(|item| item.default_variant.as_deref())
+    }
+
+    fn attributes_for(&self, type_name: &str) -> Vec<String> {
+        let mut attributes = self.attributes.clone();
+        for item in self.types.iter().filter(|item| item.name == type_name) {
+            attributes.extend(item.attributes.iter().cloned());
+        }
+        attributes
+    }
+}
+
+impl TypeResolutionResult {
+    fn all_types(&self) -> impl Iterator<Item = &RustType> {
+        self.model_types
+            .iter()
+            .chain(self.request_response_types.iter())
+    }
+}
+
 impl RustType {
-    pub fn render_stdout(&self) {
+    pub fn render_stdout(&self, out: &mut CodeWriter) {
         match (self.title.as_ref(), self.description.as_ref()) {
             (Some(title), Some(description)) => {
-                print_doc(title, 0);
-                println!("///");
-                print_doc(description, 0);
+                print_doc(out, title, 0);
+                writeln!(out, "///");
+                print_doc(out, description, 0);
             }
             (Some(title), None) => {
-                print_doc(title, 0);
+                print_doc(out, title, 0);
             }
             (None, Some(description)) => {
-                print_doc(description, 0);
+                print_doc(out, description, 0);
             }
             (None, None) => {}
         }
 
-        self.content.render_stdout(&self.name);
+        self.content
+            .render_stdout(out, &self.name, &self.derives(), &self.extra_attributes);
+    }
+
+    /// Full list of derived traits, with extra derives placed before the serde ones.
+    pub fn derives(&self) -> Vec<String> {
+        let mut derives: Vec<String> = self
+            .content
+            .default_derives()
+            .into_iter()
+            .filter(|item| !self.removed_derives.iter().any(|removed| removed == item))
+            .map(String::from)
+            .collect();
+
+        let insert_pos = derives
+            .iter()
+            .position(|item| item == "Serialize" || item == "thiserror::Error")
+            .unwrap_or(derives.len());
+        let mut extra_derives = vec![];
+        for derive in self.extra_derives.iter() {
+            if !derives.contains(derive)
+                && !extra_derives.contains(derive)
+                && !self.removed_derives.contains(derive)
+            {
+                extra_derives.push(derive.to_owned());
+            }
+        }
+        derives.splice(insert_pos..insert_pos, extra_derives);
+
+        derives
     }
 
-    pub fn render_serde_stdout(&self) {
+    pub fn render_serde_stdout(&self, out: &mut CodeWriter) {
         match &self.content {
-            RustTypeKind::Struct(content) => content.render_serde_stdout(&self.name),
-            RustTypeKind::Unit(content) => content.render_serde_stdout(&self.name),
+            RustTypeKind::Struct(content) => content.render_serde_stdout(out, &self.name),
+            RustTypeKind::Unit(content) => content.render_serde_stdout(out, &self.name),
             _ => todo!("serde blocks only implemented for structs and unit"),
         }
     }
 
+    pub fn render_arbitrary_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "#[cfg(feature = \"arbitrary\")]");
+        writeln!(
+            out,
+            "impl<'a> arbitrary::Arbitrary<'a> for {} {{",
+            self.name
+        );
+        writeln!(
+            out,
+            "    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {{"
+        );
+
+        match &self.content {
+            RustTypeKind::Struct(content) => content.render_arbitrary_body_stdout(out),
+            RustTypeKind::Enum(content) => content.render_arbitrary_body_stdout(
+                out,
+                self.derives().iter().any(|item| item == "Copy"),
+            ),
+            RustTypeKind::Wrapper(content) => content.render_arbitrary_body_stdout(out),
+            RustTypeKind::Unit(content) => content.render_arbitrary_body_stdout(out),
+            RustTypeKind::OneOf(content) => content.render_arbitrary_body_stdout(out),
+        }
+
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+
     pub fn need_custom_serde(&self) -> bool {
         match &self.content {
             RustTypeKind::Struct(content) => content.need_custom_serde(),
             RustTypeKind::Enum(content) => content.need_custom_serde(),
             RustTypeKind::Wrapper(content) => content.need_custom_serde(),
             RustTypeKind::Unit(content) => content.need_custom_serde(),
+            RustTypeKind::OneOf(content) => content.need_custom_serde(),
+        }
+    }
+
+    /// Whether any field serializer relies on the adapter of a `#[serde(with)]` module.
+    pub fn uses_adapter(&self, adapter: &SerdeWithAdapter) -> bool {
+        match &self.content {
+            RustTypeKind::Struct(content) => content.fields.iter().any(|field| {
+                field
+                    .serializer
+                    .as_ref()
+                    .is_some_and(|serializer| serializer.uses_adapter(adapter))
+            }),
+            RustTypeKind::Wrapper(content) => content
+                .serializer
+                .as_ref()
+                .is_some_and(|serializer| serializer.uses_adapter(adapter)),
+            _ => false,
+        }
+    }
+
+    /// Whether the type is generated the same way as `other`, ignoring docs.
+    pub fn is_structurally_equal(&self, other: &Self) -> bool {
+        self.without_docs() == other.without_docs()
+    }
+
+    /// Names of all types referenced by fields or variants, including the ones nested inside
+    /// generics.
+    pub fn referenced_type_names(&self) -> Vec<String> {
+        let type_names: Vec<&str> = match &self.content {
+            RustTypeKind::Struct(content) => content
+                .fields
+                .iter()
+                .flat_map(|field| {
+                    std::iter::once(field.type_name.as_str())
+                        .chain(field.borrowed_type_name.as_deref())
+                })
+                .collect(),
+            RustTypeKind::Wrapper(content) => vec![content.type_name.as_str()],
+            RustTypeKind::OneOf(content) => content
+                .variants
+                .iter()
+                .flat_map(|variant| {
+                    std::iter::once(variant.type_name.as_str())
+                        .chain(variant.borrowed_type_name.as_deref())
+                })
+                .collect(),
+            RustTypeKind::Enum(_) | RustTypeKind::Unit(_) => vec![],
+        };
+
+        type_names
+            .into_iter()
+            .flat_map(|type_name| IDENTIFIER.find_iter(type_name))
+            .map(|item| item.as_str().to_owned())
+            .collect()
+    }
+
+    fn without_docs(&self) -> Self {
+        let mut rust_type = self.clone();
+        rust_type.title = None;
+        rust_type.description = None;
+        match &mut rust_type.content {
+            RustTypeKind::Struct(content) => content
+                .fields
+                .iter_mut()
+                .for_each(|field| field.description = None),
+            RustTypeKind::Enum(content) => content
+                .variants
+                .iter_mut()
+                .for_each(|variant| variant.description = None),
+            RustTypeKind::OneOf(content) => content
+                .variants
+                .iter_mut()
+                .for_each(|variant| variant.description = None),
+            RustTypeKind::Wrapper(_) | RustTypeKind::Unit(_) => {}
+        }
+        rust_type
+    }
+
+    /// Whether any manual serde implementation is needed, either for the type itself or its
+    /// `Ref` and `Borrowed` variants.
+    pub fn need_serde_impls(&self) -> bool {
+        match &self.content {
+            RustTypeKind::Struct(content) => {
+                content.need_custom_serde()
+                    || content.need_ref_serialize()
+                    || content.need_borrowed_deserialize()
+            }
+            _ => self.need_custom_serde(),
         }
     }
 }
 
 impl RustTypeKind {
-    pub fn render_stdout(&self, name: &str) {
+    /// Name of the kind of type generated, for messages.
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            Self::Struct(_) => "struct",
+            Self::Enum(_) => "enum",
+            Self::Wrapper(_) => "wrapper",
+            Self::Unit(_) => "unit struct",
+            Self::OneOf(_) => "`oneOf` enum",
+        }
+    }
+
+    pub fn render_stdout(
+        &self,
+        out: &mut CodeWriter,
+        name: &str,
+        derives: &[String],
+        attributes: &[String],
+    ) {
+        match self {
+            Self::Struct(value) => value.render_stdout(out, name, derives, attributes),
+            Self::Enum(value) => value.render_stdout(out, name, derives, attributes),
+            Self::Wrapper(value) => value.render_stdout(out, name, derives, attributes),
+            Self::Unit(value) => value.render_stdout(out, name, derives, attributes),
+            Self::OneOf(value) => value.render_stdout(out, name, derives, attributes),
+        }
+    }
+
+    pub fn default_derives(&self) -> Vec<&'static str> {
         match self {
-            Self::Struct(value) => value.render_stdout(name),
-            Self::Enum(value) => value.render_stdout(name),
-            Self::Wrapper(value) => value.render_stdout(name),
-            Self::Unit(value) => value.render_stdout(name),
+            Self::Struct(value) => value.default_derives(),
+            Self::Enum(value) => value.default_derives(),
+            Self::Wrapper(value) => value.default_derives(),
+            Self::Unit(value) => value.default_derives(),
+            Self::OneOf(value) => value.default_derives(),
         }
     }
 }
 
 impl RustStruct {
-    pub fn render_stdout(&self, name: &str) {
+    pub fn render_stdout(
+        &self,
+        out: &mut CodeWriter,
+        name: &str,
+        derives: &[String],
+        attributes: &[String],
+    ) {
         let derive_serde = !self.need_custom_serde();
 
         if derive_serde
@@ -274,176 +1003,455 @@ impl RustStruct {
                 .iter()
                 .any(|item| matches!(item.serializer, Some(SerializerOverride::SerdeAs(_))))
         {
-            println!("#[serde_as]");
+            writeln!(out, "#[serde_as]");
         }
+        print_derives(out, derives);
         if derive_serde {
-            println!("#[derive(Debug, Clone, Serialize, Deserialize)]");
-            println!("#[cfg_attr(feature = \"no_unknown_fields\", serde(deny_unknown_fields))]");
-        } else {
-            println!("#[derive(Debug, Clone)]");
+            writeln!(
+                out,
+                "#[cfg_attr(feature = \"no_unknown_fields\", serde(deny_unknown_fields))]"
+            );
         }
-        println!("pub struct {name} {{");
+        print_attributes(out, attributes);
+        writeln!(out, "pub struct {name} {{");
 
         for field in self.fields.iter().filter(|field| field.fixed.is_none()) {
             if let Some(doc) = &field.description {
-                print_doc(doc, 4);
+                print_doc(out, doc, 4);
             }
 
             for line in field.def_lines(4, derive_serde, false, false) {
-                println!("{line}")
+                writeln!(out, "{line}")
             }
         }
 
-        println!("}}");
+        writeln!(out, "}}");
 
         if self.extra_ref_type {
-            println!();
+            writeln!(out);
 
-            print_doc(&format!("Reference version of [{}].", name), 0);
-            println!("#[derive(Debug, Clone)]");
-            println!("pub struct {name}Ref<'a> {{");
+            print_doc(out, &format!("Reference version of [{}].", name), 0);
+            writeln!(out, "#[derive(Debug, Clone)]");
+            writeln!(out, "pub struct {name}Ref<'a> {{");
 
             for field in self.fields.iter().filter(|field| field.fixed.is_none()) {
-                for line in field.def_lines(4, false, true, false) {
-                    println!("{line}")
+                if self.params.is_some() {
+                    for line in field.def_lines(4, false, true, false) {
+                        writeln!(out, "{line}")
+                    }
+                } else {
+                    if let Some(doc) = &field.description {
+                        print_doc(out, doc, 4);
+                    }
+                    writeln!(
+                        out,
+                        "    pub {}: {},",
+                        escape_name(&field.name),
+                        field.model_ref_type_name()
+                    );
                 }
             }
 
-            println!("}}");
+            writeln!(out, "}}");
         }
-    }
-
-    pub fn render_serde_stdout(&self, name: &str) {
-        self.render_impl_serialize_stdout(name);
-        println!();
-        self.render_impl_deserialize_stdout(name);
-    }
 
-    pub fn need_custom_serde(&self) -> bool {
-        self.serde_as_array || self.fields.iter().any(|field| field.fixed.is_some())
-    }
+        if self.extra_borrowed_type {
+            writeln!(out);
 
-    fn render_impl_serialize_stdout(&self, name: &str) {
-        if self.serde_as_array {
-            self.render_impl_array_serialize_stdout(name);
-        } else {
-            self.render_impl_tagged_serialize_stdout(name);
-        }
-    }
+            print_doc(
+                out,
+                &format!(
+                    "Zero-copy version of [{}] for deserialization, borrowing strings from the \
+                    input and parsing field elements lazily.",
+                    name
+                ),
+                0,
+            );
+            if derive_serde && self.fields.iter().any(|field| field.borrowed_serde_as()) {
+                writeln!(out, "#[serde_as]");
+            }
+            if derive_serde {
+                writeln!(out, "#[derive(Debug, Clone, Deserialize)]");
+                writeln!(
+                    out,
+                    "#[cfg_attr(feature = \"no_unknown_fields\", serde(deny_unknown_fields))]"
+                );
+            } else {
+                writeln!(out, "#[derive(Debug, Clone)]");
+            }
+            writeln!(out, "pub struct {name}Borrowed<'a> {{");
 
-    fn render_impl_deserialize_stdout(&self, name: &str) {
-        if self.serde_as_array {
-            self.render_impl_array_deserialize_stdout(name);
-        } else {
-            self.render_impl_tagged_deserialize_stdout(name);
-        }
-    }
+            for field in self.fields.iter().filter(|field| field.fixed.is_none()) {
+                if let Some(doc) = &field.description {
+                    print_doc(out, doc, 4);
+                }
 
-    fn render_impl_array_serialize_stdout(&self, name: &str) {
-        self.render_impl_array_serialize_stdout_inner(name, false);
+                for line in field.borrowed_def_lines(4, derive_serde, false) {
+                    writeln!(out, "{line}")
+                }
+            }
 
-        if self.extra_ref_type {
-            println!();
-            self.render_impl_array_serialize_stdout_inner(name, true);
+            writeln!(out, "}}");
         }
     }
 
-    fn render_impl_array_serialize_stdout_inner(&self, name: &str, is_ref_type: bool) {
-        println!(
-            "impl{} Serialize for {}{} {{",
-            if is_ref_type { "<'a>" } else { "" },
-            name,
-            if is_ref_type { "Ref<'a>" } else { "" },
-        );
-        println!(
-            "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
-        );
-
-        for (ind_field, field) in self.fields.iter().enumerate() {
-            if field.serializer.is_some() {
-                println!("        #[serde_as]");
-            }
+    pub fn render_serde_stdout(&self, out: &mut CodeWriter, name: &str) {
+        if self.need_custom_serde() {
+            self.render_impl_serialize_stdout(out, name);
+            writeln!(out);
+            self.render_impl_deserialize_stdout(out, name);
+        }
 
-            println!("        #[derive(Serialize)]");
-            println!("        #[serde(transparent)]");
-            println!("        struct Field{}<'a> {{", ind_field);
-            for line in field.def_lines(12, true, true, false).iter() {
-                println!("{line}");
+        if self.need_ref_serialize() {
+            if self.need_custom_serde() {
+                writeln!(out);
             }
-            println!("        }}");
-            println!();
+            self.render_impl_ref_tagged_serialize_stdout(out, name);
         }
 
-        println!("        use serde::ser::SerializeSeq;");
-        println!();
-        println!("        let mut seq = serializer.serialize_seq(None)?;");
-        println!();
-
-        for (ind_field, field) in self.fields.iter().enumerate() {
-            if field.name.len() > 5 {
-                println!("        seq.serialize_element(&Field{} {{", ind_field);
-                println!(
-                    "            {}: {}self.{},",
-                    field.name,
-                    if is_ref_type { "" } else { "&" },
-                    field.name
-                );
-                println!("        }})?;");
-            } else {
-                println!(
-                    "        seq.serialize_element(&Field{} {{ {}: {}self.{} }})?;",
-                    ind_field,
-                    field.name,
-                    if is_ref_type { "" } else { "&" },
-                    field.name
-                );
-            }
+        if self.need_borrowed_deserialize() {
+            writeln!(out);
+            self.render_impl_tagged_deserialize_stdout(out, name, true);
         }
+    }
 
-        println!();
-        println!("        seq.end()");
+    /// Whether a `Borrowed` variant is generated that can't derive `Deserialize` due to fixed
+    /// fields.
+    pub fn need_borrowed_deserialize(&self) -> bool {
+        self.extra_borrowed_type && self.need_custom_serde()
+    }
 
-        println!("    }}");
-        println!("}}");
+    /// Whether a `Ref` variant of a model type is generated, which always needs a manual
+    /// `Serialize` implementation. `Ref` variants of requests are handled in the array
+    /// serialization instead.
+    pub fn need_ref_serialize(&self) -> bool {
+        self.extra_ref_type && self.params.is_none()
     }
 
-    fn render_impl_tagged_serialize_stdout(&self, name: &str) {
-        println!("impl Serialize for {name} {{");
-        println!(
-            "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
-        );
+    pub fn default_derives(&self) -> Vec<&'static str> {
+        if self.need_custom_serde() {
+            vec!["Debug", "Clone"]
+        } else {
+            vec!["Debug", "Clone", "Serialize", "Deserialize"]
+        }
+    }
 
-        if self
+    pub fn need_custom_serde(&self) -> bool {
+        self.params.is_some() || self.fields.iter().any(|field| field.fixed.is_some())
+    }
+
+    pub fn render_builder_stdout(&self, out: &mut CodeWriter, name: &str) {
+        let fields = self
             .fields
             .iter()
-            .any(|item| matches!(item.serializer, Some(SerializerOverride::SerdeAs(_))))
-        {
-            println!("        #[serde_as]");
+            .filter(|field| field.fixed.is_none())
+            .collect::<Vec<_>>();
+
+        print_doc(out, &format!("Builder for [{name}]."), 0);
+        writeln!(out, "#[derive(Debug, Clone, Default)]");
+        writeln!(out, "pub struct {name}Builder {{");
+        for field in fields.iter() {
+            // Optional fields are already `Option<T>`
+            let type_name = field.type_name_with_wrapping();
+            writeln!(
+                out,
+                "    {}: {},",
+                escape_name(&field.name),
+                if field.is_option() {
+                    type_name
+                } else {
+                    format!("Option<{type_name}>")
+                }
+            );
+        }
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl {name} {{");
+        print_doc(out, &format!("Creates a builder for [{name}]."), 4);
+        writeln!(out, "    pub fn builder() -> {name}Builder {{");
+        writeln!(out, "        {name}Builder::default()");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+
+        writeln!(out, "impl {name}Builder {{");
+        for field in fields.iter() {
+            let setter_type = match strip_generic(&field.type_name, "Option") {
+                Some(inner) if field.is_option() => inner.to_owned(),
+                _ => field.type_name.clone(),
+            };
+            let setter_type = if field.arc_wrap {
+                format!("Arc<{setter_type}>")
+            } else {
+                setter_type
+            };
+
+            if let Some(doc) = &field.description {
+                print_doc(out, doc, 4);
+            }
+            writeln!(
+                out,
+                "    pub fn {}(mut self, {}: {}) -> Self {{",
+                escape_name(&field.name),
+                escape_name(&field.name),
+                setter_type
+            );
+            writeln!(
+                out,
+                "        self.{} = Some({});",
+                escape_name(&field.name),
+                escape_name(&field.name)
+            );
+            writeln!(out, "        self");
+            writeln!(out, "    }}");
+            writeln!(out);
+        }
+
+        print_doc(
+            out,
+            &format!(
+                "Builds [{name}], failing with [BuilderError] if a required field is missing."
+            ),
+            4,
+        );
+        writeln!(
+            out,
+            "    pub fn build(self) -> Result<{name}, BuilderError> {{"
+        );
+        writeln!(out, "        Ok({name} {{");
+        for field in fields.iter() {
+            if field.is_option() {
+                writeln!(
+                    out,
+                    "            {}: self.{},",
+                    escape_name(&field.name),
+                    escape_name(&field.name)
+                );
+            } else {
+                writeln!(
+                    out,
+                    "            {}: self.{}.ok_or(BuilderError {{",
+                    escape_name(&field.name),
+                    escape_name(&field.name)
+                );
+                writeln!(out, "                type_name: \"{name}\",");
+                writeln!(out, "                field: \"{}\",", field.name);
+                writeln!(out, "            }})?,");
+            }
+        }
+        writeln!(out, "        }})");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+
+    fn render_arbitrary_body_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "        Ok(Self {{");
+
+        // Fixed fields are not part of the struct and are always serialized with their fixed
+        // values, so they're skipped here.
+        for field in self.fields.iter().filter(|field| field.fixed.is_none()) {
+            writeln!(
+                out,
+                "            {}: {},",
+                escape_name(&field.name),
+                field.arbitrary_expr()
+            );
+        }
+
+        writeln!(out, "        }})");
+    }
+
+    fn render_impl_serialize_stdout(&self, out: &mut CodeWriter, name: &str) {
+        if self.params.is_some() {
+            self.render_impl_array_serialize_stdout(out, name);
+        } else {
+            self.render_impl_tagged_serialize_stdout(out, name);
+        }
+    }
+
+    fn render_impl_deserialize_stdout(&self, out: &mut CodeWriter, name: &str) {
+        if self.params.is_some() {
+            self.render_impl_array_deserialize_stdout(out, name);
+        } else {
+            self.render_impl_tagged_deserialize_stdout(out, name, false);
+        }
+    }
+
+    fn render_impl_array_serialize_stdout(&self, out: &mut CodeWriter, name: &str) {
+        self.render_impl_array_serialize_stdout_inner(out, name, false);
+
+        if self.extra_ref_type {
+            writeln!(out);
+            self.render_impl_array_serialize_stdout_inner(out, name, true);
+        }
+    }
+
+    fn render_impl_array_serialize_stdout_inner(
+        &self,
+        out: &mut CodeWriter,
+        name: &str,
+        is_ref_type: bool,
+    ) {
+        writeln!(
+            out,
+            "impl{} Serialize for {}{} {{",
+            if is_ref_type { "<'a>" } else { "" },
+            name,
+            if is_ref_type { "Ref<'a>" } else { "" },
+        );
+        writeln!(
+            out,
+            "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        );
+
+        for (ind_field, field) in self.fields.iter().enumerate() {
+            if field.serializer.is_some() {
+                writeln!(out, "        #[serde_as]");
+            }
+
+            writeln!(out, "        #[derive(Serialize)]");
+            writeln!(out, "        #[serde(transparent)]");
+            writeln!(out, "        struct Field{}<'a> {{", ind_field);
+            for line in field.def_lines(12, true, true, false).iter() {
+                writeln!(out, "{line}");
+            }
+            writeln!(out, "        }}");
+            writeln!(out);
+        }
+
+        if self.params == Some(ParamStructure::ByName) {
+            self.render_params_map_stdout(out, is_ref_type);
+            writeln!(out, "    }}");
+            writeln!(out, "}}");
+            return;
+        }
+
+        writeln!(out, "        use serde::ser::SerializeSeq;");
+        writeln!(out);
+        writeln!(
+            out,
+            "        let mut seq = serializer.serialize_seq(None)?;"
+        );
+        writeln!(out);
+
+        for (ind_field, field) in self.fields.iter().enumerate() {
+            if field.name.len() > 5 {
+                writeln!(out, "        seq.serialize_element(&Field{} {{", ind_field);
+                writeln!(
+                    out,
+                    "            {}: {}self.{},",
+                    field.name,
+                    if is_ref_type { "" } else { "&" },
+                    field.name
+                );
+                writeln!(out, "        }})?;");
+            } else {
+                writeln!(
+                    out,
+                    "        seq.serialize_element(&Field{} {{ {}: {}self.{} }})?;",
+                    ind_field,
+                    field.name,
+                    if is_ref_type { "" } else { "&" },
+                    field.name
+                );
+            }
+        }
+
+        writeln!(out);
+        writeln!(out, "        seq.end()");
+
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+
+    /// Renders the body of a `Serialize` implementation for params passed by name, using the
+    /// same `FieldN` wrappers as positional params. `None` values of optional params are
+    /// omitted unless configured otherwise.
+    fn render_params_map_stdout(&self, out: &mut CodeWriter, is_ref_type: bool) {
+        writeln!(out, "        use serde::ser::SerializeMap;");
+        writeln!(out);
+        writeln!(
+            out,
+            "        let mut map = serializer.serialize_map(None)?;"
+        );
+        writeln!(out);
+
+        for (ind_field, field) in self.fields.iter().enumerate() {
+            let indent = if field.is_option() && !field.serialize_none {
+                writeln!(out, "        if self.{}.is_some() {{", field.name);
+                "    "
+            } else {
+                ""
+            };
+            let reference = if is_ref_type { "" } else { "&" };
+            if field.name.len() > 5 {
+                writeln!(out, "        {indent}map.serialize_entry(");
+                writeln!(out, "        {indent}    \"{}\",", field.name);
+                writeln!(out, "        {indent}    &Field{} {{", ind_field);
+                writeln!(
+                    out,
+                    "        {indent}        {}: {}self.{},",
+                    field.name, reference, field.name
+                );
+                writeln!(out, "        {indent}    }},");
+                writeln!(out, "        {indent})?;");
+            } else {
+                writeln!(
+                    out,
+                    "        {}map.serialize_entry(\"{}\", &Field{} {{ {}: {}self.{} }})?;",
+                    indent, field.name, ind_field, field.name, reference, field.name
+                );
+            }
+            if !indent.is_empty() {
+                writeln!(out, "        }}");
+            }
+        }
+
+        writeln!(out);
+        writeln!(out, "        map.end()");
+    }
+
+    fn render_impl_tagged_serialize_stdout(&self, out: &mut CodeWriter, name: &str) {
+        writeln!(out, "impl Serialize for {name} {{");
+        writeln!(
+            out,
+            "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        );
+
+        if self
+            .fields
+            .iter()
+            .any(|item| matches!(item.serializer, Some(SerializerOverride::SerdeAs(_))))
+        {
+            writeln!(out, "        #[serde_as]");
         }
 
-        println!("        #[derive(Serialize)]");
-        println!("        struct Tagged<'a> {{");
+        writeln!(out, "        #[derive(Serialize)]");
+        writeln!(out, "        struct Tagged<'a> {{");
 
         for field in self.fields.iter() {
             for line in field.def_lines(12, true, true, false).iter() {
-                println!("{line}");
+                writeln!(out, "{line}");
             }
         }
 
-        println!("        }}");
-        println!();
-        println!("        let tagged = Tagged {{");
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        let tagged = Tagged {{");
 
         for field in self.fields.iter() {
             match &field.fixed {
-                Some(fixed_field) => {
-                    println!(
+                Some(fixed_value) => {
+                    writeln!(
+                        out,
                         "            {}: {},",
                         escape_name(&field.name),
-                        fixed_field.value
+                        fixed_value
                     )
                 }
-                None => println!(
+                None => writeln!(
+                    out,
                     "            {}: &self.{},",
                     escape_name(&field.name),
                     escape_name(&field.name)
@@ -451,114 +1459,259 @@ impl RustStruct {
             }
         }
 
-        println!("        }};");
-        println!();
-        println!("        Tagged::serialize(&tagged, serializer)");
+        writeln!(out, "        }};");
+        writeln!(out);
+        writeln!(out, "        Tagged::serialize(&tagged, serializer)");
+
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+
+    fn render_impl_ref_tagged_serialize_stdout(&self, out: &mut CodeWriter, name: &str) {
+        writeln!(out, "impl<'a> Serialize for {name}Ref<'a> {{");
+        writeln!(
+            out,
+            "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        );
+
+        if self.fields.iter().any(|item| {
+            !item.nested_ref && matches!(item.serializer, Some(SerializerOverride::SerdeAs(_)))
+        }) {
+            writeln!(out, "        #[serde_as]");
+        }
+
+        writeln!(out, "        #[derive(Serialize)]");
+        writeln!(out, "        struct Tagged<'a> {{");
+
+        for field in self.fields.iter() {
+            let lines = match field.borrowed_option_field() {
+                Some(borrowed) => borrowed.def_lines(12, true, false, true),
+                None => field.def_lines(12, true, true, false),
+            };
+            for line in lines.iter() {
+                writeln!(out, "{line}");
+            }
+        }
+
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(out, "        let tagged = Tagged {{");
+
+        for field in self.fields.iter() {
+            match &field.fixed {
+                Some(fixed_value) => {
+                    writeln!(
+                        out,
+                        "            {}: {},",
+                        escape_name(&field.name),
+                        fixed_value
+                    )
+                }
+                None => writeln!(
+                    out,
+                    "            {}: {}self.{},",
+                    escape_name(&field.name),
+                    // `Cow` and nested `Ref` fields are owned by `self`, while references and
+                    // `Option`s of references are copied
+                    if field.model_ref_type_name().starts_with('&')
+                        || field.borrowed_option_type_name().is_some()
+                    {
+                        ""
+                    } else {
+                        "&"
+                    },
+                    escape_name(&field.name)
+                ),
+            }
+        }
 
-        println!("    }}");
-        println!("}}");
+        writeln!(out, "        }};");
+        writeln!(out);
+        writeln!(out, "        Tagged::serialize(&tagged, serializer)");
+
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
     }
 
-    fn render_impl_array_deserialize_stdout(&self, name: &str) {
-        println!("impl<'de> Deserialize<'de> for {name} {{");
-        println!("    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{");
+    fn render_impl_array_deserialize_stdout(&self, out: &mut CodeWriter, name: &str) {
+        writeln!(out, "impl<'de> Deserialize<'de> for {name} {{");
+        writeln!(out, "    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{");
 
-        println!("        #[serde_as]");
-        println!("        #[derive(Deserialize)]");
-        println!("        struct AsObject {{");
+        writeln!(out, "        #[serde_as]");
+        writeln!(out, "        #[derive(Deserialize)]");
+        writeln!(out, "        struct AsObject {{");
 
         for field in self.fields.iter() {
             for line in field.def_lines(12, true, false, false).iter() {
-                println!("{line}");
+                writeln!(out, "{line}");
             }
         }
 
-        println!("        }}");
-        println!();
+        writeln!(out, "        }}");
+        writeln!(out);
 
         for (ind_field, field) in self.fields.iter().enumerate() {
             if field.serializer.is_some() {
-                println!("        #[serde_as]");
+                writeln!(out, "        #[serde_as]");
             }
 
-            println!("        #[derive(Deserialize)]");
-            println!("        #[serde(transparent)]");
-            println!("        struct Field{} {{", ind_field);
+            writeln!(out, "        #[derive(Deserialize)]");
+            writeln!(out, "        #[serde(transparent)]");
+            writeln!(out, "        struct Field{} {{", ind_field);
             for line in field.def_lines(12, true, false, false).iter() {
-                println!("{line}");
+                writeln!(out, "{line}");
             }
-            println!("        }}");
-            println!();
+            writeln!(out, "        }}");
+            writeln!(out);
         }
 
-        println!("        let temp = serde_json::Value::deserialize(deserializer)?;");
-        println!();
-        println!(
-            "        if let Ok(mut elements) = Vec::<serde_json::Value>::deserialize(&temp) {{"
+        writeln!(
+            out,
+            "        let temp = serde_json::Value::deserialize(deserializer)?;"
+        );
+        writeln!(out);
+        writeln!(
+            out,
+            "        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {{"
+        );
+        writeln!(
+            out,
+            "            if elements.len() > {} {{",
+            self.fields.len()
+        );
+        writeln!(
+            out,
+            "                return Err(serde::de::Error::custom(\"invalid sequence length\"));"
         );
+        writeln!(out, "            }}");
+        writeln!(out, "            let mut elements = elements.into_iter();");
+        writeln!(out);
 
-        for (ind_field, _) in self.fields.iter().enumerate().rev() {
-            println!(
-                "            let field{} = serde_json::from_value::<Field{}>(",
-                ind_field, ind_field
-            );
-            println!("                elements");
-            println!("                    .pop()");
-            println!("                    .ok_or_else(|| serde::de::Error::custom(\"invalid sequence length\"))?,");
-            println!("            )");
-            println!("            .map_err(|err| serde::de::Error::custom(format!(\"failed to parse element: {{}}\", err)))?;");
+        // Trailing optional params can be left out
+        for (ind_field, field) in self.fields.iter().enumerate() {
+            if field.is_option() {
+                writeln!(out, "            let field{ind_field} = elements");
+                writeln!(out, "                .next()");
+                writeln!(
+                    out,
+                    "                .map(serde_json::from_value::<Field{ind_field}>)"
+                );
+                writeln!(out, "                .transpose()");
+                writeln!(out, "                .map_err(|err| {{");
+                writeln!(
+                    out,
+                    "                    serde::de::Error::custom(format!(\"failed to parse element: {{}}\", err))"
+                );
+                writeln!(out, "                }})?;");
+            } else {
+                writeln!(
+                    out,
+                    "            let field{} = serde_json::from_value::<Field{}>(",
+                    ind_field, ind_field
+                );
+                writeln!(out, "                elements");
+                writeln!(out, "                    .next()");
+                writeln!(out, "                    .ok_or_else(|| serde::de::Error::custom(\"invalid sequence length\"))?,");
+                writeln!(out, "            )");
+                writeln!(out, "            .map_err(|err| serde::de::Error::custom(format!(\"failed to parse element: {{}}\", err)))?;");
+            }
         }
 
-        println!();
+        writeln!(out);
 
-        println!("            Ok(Self {{");
+        writeln!(out, "            Ok(Self {{");
 
         for (ind_field, field) in self.fields.iter().enumerate() {
-            println!(
-                "                {}: field{}.{},",
-                field.name, ind_field, field.name
-            );
+            if field.is_option() {
+                writeln!(
+                    out,
+                    "                {}: field{}.and_then(|field| field.{}),",
+                    field.name, ind_field, field.name
+                );
+            } else {
+                writeln!(
+                    out,
+                    "                {}: field{}.{},",
+                    field.name, ind_field, field.name
+                );
+            }
         }
 
-        println!("            }})");
+        writeln!(out, "            }})");
 
-        println!("        }} else if let Ok(object) = AsObject::deserialize(&temp) {{");
+        writeln!(out, "        }} else {{");
+        writeln!(out, "            let object = AsObject::deserialize(&temp)");
+        writeln!(
+            out,
+            "                .map_err(|err| serde::de::Error::custom(format!(\"invalid params: {{}}\", err)))?;"
+        );
+        writeln!(out);
 
-        println!("            Ok(Self {{");
+        writeln!(out, "            Ok(Self {{");
 
         for field in self.fields.iter() {
-            println!("                {}: object.{},", field.name, field.name);
+            writeln!(
+                out,
+                "                {}: object.{},",
+                field.name, field.name
+            );
         }
 
-        println!("            }})");
-
-        println!("        }} else {{");
-        println!("            Err(serde::de::Error::custom(\"invalid sequence length\"))");
-        println!("        }}");
+        writeln!(out, "            }})");
+        writeln!(out, "        }}");
 
-        println!("    }}");
-        println!("}}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
     }
 
-    fn render_impl_tagged_deserialize_stdout(&self, name: &str) {
-        println!("impl<'de> Deserialize<'de> for {name} {{");
-        println!("    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{");
+    /// Renders a `Deserialize` implementation validating fixed fields, either for the type
+    /// itself or for its `Borrowed` variant.
+    fn render_impl_tagged_deserialize_stdout(
+        &self,
+        out: &mut CodeWriter,
+        name: &str,
+        borrowed: bool,
+    ) {
+        if borrowed {
+            writeln!(
+                out,
+                "impl<'de: 'a, 'a> Deserialize<'de> for {name}Borrowed<'a> {{"
+            );
+        } else {
+            writeln!(out, "impl<'de> Deserialize<'de> for {name} {{");
+        }
+        writeln!(out, "    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{");
 
-        if self
-            .fields
-            .iter()
-            .any(|item| matches!(item.serializer, Some(SerializerOverride::SerdeAs(_))))
-        {
-            println!("        #[serde_as]");
+        let serde_as = if borrowed {
+            self.fields.iter().any(|item| item.borrowed_serde_as())
+        } else {
+            self.fields
+                .iter()
+                .any(|item| matches!(item.serializer, Some(SerializerOverride::SerdeAs(_))))
+        };
+        if serde_as {
+            writeln!(out, "        #[serde_as]");
         }
 
-        println!("        #[derive(Deserialize)]");
-        println!(
+        writeln!(out, "        #[derive(Deserialize)]");
+        writeln!(
+            out,
             "        #[cfg_attr(feature = \"no_unknown_fields\", serde(deny_unknown_fields))]"
         );
-        println!("        struct Tagged {{");
+        if borrowed {
+            writeln!(out, "        struct Tagged<'a> {{");
+        } else {
+            writeln!(out, "        struct Tagged {{");
+        }
 
         for field in self.fields.iter() {
+            if borrowed {
+                for line in field.borrowed_def_lines(12, true, field.fixed.is_some()) {
+                    writeln!(out, "{line}");
+                }
+                continue;
+            }
+
             let lines = match &field.fixed {
                 Some(_) => RustField {
                     description: field.description.clone(),
@@ -569,44 +1722,65 @@ impl RustStruct {
                     type_name: format!("Option<{}>", field.type_name),
                     serde_rename: field.serde_rename.clone(),
                     serde_faltten: field.serde_faltten,
-                    serializer: field.serializer.as_ref().map(|value| value.to_optional()),
+                    serializer: field.serializer.as_ref().map(|value| {
+                        value
+                            .to_optional()
+                            .expect("Fixed field serializer cannot be made optional")
+                    }),
+                    nested_ref: false,
+                    borrowed_type_name: None,
+                    nullable: false,
+                    serialize_none: false,
+                    serde_default: false,
+                    flattened_from: vec![],
                 }
                 .def_lines(12, true, false, true),
                 None => field.def_lines(12, true, false, true),
             };
 
             for line in lines.iter() {
-                println!("{line}");
+                writeln!(out, "{line}");
             }
         }
 
-        println!("        }}");
-        println!();
-        println!("        let tagged = Tagged::deserialize(deserializer)?;");
-        println!();
+        writeln!(out, "        }}");
+        writeln!(out);
+        writeln!(
+            out,
+            "        let tagged = Tagged::deserialize(deserializer)?;"
+        );
+        writeln!(out);
+
+        for field in self.fields.iter() {
+            let fixed_value = match &field.fixed {
+                Some(fixed_value) => fixed_value,
+                None => continue,
+            };
 
-        for fixed_field in self.fields.iter().filter_map(|field| field.fixed.as_ref()) {
-            println!(
+            writeln!(
+                out,
                 "        if let Some(tag_field) = &tagged.{} {{",
-                escape_name(fixed_field.name)
+                escape_name(&field.name)
             );
-            println!("            if tag_field != {} {{", fixed_field.value);
-            println!(
+            writeln!(out, "            if tag_field != {} {{", fixed_value);
+            writeln!(
+                out,
                 "                return Err(serde::de::Error::custom(\"invalid `{}` value\"));",
-                fixed_field.name
+                field.name
             );
-            println!("            }}");
-            println!("        }}");
-            println!();
+            writeln!(out, "            }}");
+            writeln!(out, "        }}");
+            writeln!(out);
         }
 
-        println!("        Ok(Self {{");
+        writeln!(out, "        Ok(Self {{");
 
         for field in self.fields.iter().filter(|field| field.fixed.is_none()) {
-            println!(
+            writeln!(
+                out,
                 "            {}: {},",
                 escape_name(&field.name),
-                if field.arc_wrap {
+                if field.arc_wrap && !borrowed {
                     format!("Arc::new(tagged.{})", escape_name(&field.name))
                 } else {
                     format!("tagged.{}", escape_name(&field.name))
@@ -614,135 +1788,533 @@ impl RustStruct {
             );
         }
 
-        println!("        }})");
+        writeln!(out, "        }})");
 
-        println!("    }}");
-        println!("}}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
     }
 }
 
 impl RustEnum {
-    pub fn render_stdout(&self, name: &str) {
-        println!(
-            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize{})]",
-            if self.thiserror {
-                ", thiserror::Error"
-            } else {
-                ""
-            }
-        );
-        println!("pub enum {name} {{");
+    pub fn render_stdout(
+        &self,
+        out: &mut CodeWriter,
+        name: &str,
+        derives: &[String],
+        attributes: &[String],
+    ) {
+        print_derives(out, derives);
+        print_attributes(out, attributes);
+        if self.variants.is_empty() {
+            writeln!(out, "pub enum {name} {{}}");
+            return;
+        }
+        writeln!(out, "pub enum {name} {{");
 
         for variant in self.variants.iter() {
             if let Some(doc) = &variant.description {
-                print_doc(doc, 4);
+                print_doc(out, doc, 4);
             }
 
             if let Some(rename) = &variant.serde_name {
-                println!("    #[serde(rename = \"{rename}\")]");
+                writeln!(out, "    #[serde(rename = \"{rename}\")]");
             }
             if let Some(err) = &variant.error_text {
-                println!("    #[error(\"{err}\")]");
+                writeln!(out, "    #[error(\"{err}\")]");
+            }
+            if self.default_variant.as_ref() == Some(&variant.name) {
+                writeln!(out, "    #[default]");
             }
-            println!("    {},", variant.name);
+            writeln!(out, "    {},", variant.name);
         }
 
-        println!("}}");
+        writeln!(out, "}}");
+    }
+
+    pub fn default_derives(&self) -> Vec<&'static str> {
+        let mut derives = vec![
+            "Debug",
+            "Clone",
+            "Copy",
+            "PartialEq",
+            "Eq",
+            "Serialize",
+            "Deserialize",
+        ];
+        if self.thiserror {
+            derives.push("thiserror::Error");
+        }
+        derives
     }
 
     pub fn need_custom_serde(&self) -> bool {
         false
     }
+
+    fn render_arbitrary_body_stdout(&self, out: &mut CodeWriter, is_copy: bool) {
+        writeln!(
+            out,
+            "        Ok({}u.choose(&[",
+            if is_copy { "*" } else { "" }
+        );
+
+        for variant in self.variants.iter() {
+            writeln!(out, "            Self::{},", variant.name);
+        }
+
+        writeln!(out, "        ])?{})", if is_copy { "" } else { ".clone()" });
+    }
 }
 
 impl RustWrapper {
-    pub fn render_stdout(&self, name: &str) {
-        println!("#[derive(Debug, Clone, Serialize, Deserialize)]");
-        println!("pub struct {}(pub {});", name, self.type_name);
+    pub fn render_stdout(
+        &self,
+        out: &mut CodeWriter,
+        name: &str,
+        derives: &[String],
+        attributes: &[String],
+    ) {
+        if matches!(self.serializer, Some(SerializerOverride::SerdeAs(_))) {
+            writeln!(out, "#[serde_as]");
+        }
+        print_derives(out, derives);
+        print_attributes(out, attributes);
+        match &self.serializer {
+            Some(serializer) => writeln!(
+                out,
+                "pub struct {}({} pub {});",
+                name,
+                serializer.attribute(),
+                self.type_name
+            ),
+            None => writeln!(out, "pub struct {}(pub {});", name, self.type_name),
+        }
+    }
+
+    pub fn default_derives(&self) -> Vec<&'static str> {
+        vec!["Debug", "Clone", "Serialize", "Deserialize"]
     }
 
     pub fn need_custom_serde(&self) -> bool {
         false
     }
+
+    fn render_arbitrary_body_stdout(&self, out: &mut CodeWriter) {
+        writeln!(
+            out,
+            "        Ok(Self({}))",
+            to_arbitrary_expr(&self.type_name, "u.arbitrary()?")
+        );
+    }
 }
 
 impl RustUnit {
-    pub fn render_stdout(&self, name: &str) {
+    pub fn render_stdout(
+        &self,
+        out: &mut CodeWriter,
+        name: &str,
+        derives: &[String],
+        attributes: &[String],
+    ) {
+        print_derives(out, derives);
+        print_attributes(out, attributes);
+        writeln!(out, "pub struct {};", name);
+    }
+
+    pub fn default_derives(&self) -> Vec<&'static str> {
         if self.need_custom_serde() {
-            println!("#[derive(Debug, Clone)]");
+            vec!["Debug", "Clone"]
         } else {
-            println!("#[derive(Debug, Clone, Serialize, Deserialize)]");
+            vec!["Debug", "Clone", "Serialize", "Deserialize"]
         }
-        println!("pub struct {};", name);
     }
 
-    pub fn render_serde_stdout(&self, name: &str) {
-        self.render_impl_serialize_stdout(name);
-        println!();
-        self.render_impl_deserialize_stdout(name);
+    pub fn render_serde_stdout(&self, out: &mut CodeWriter, name: &str) {
+        self.render_impl_serialize_stdout(out, name);
+        writeln!(out);
+        self.render_impl_deserialize_stdout(out, name);
     }
 
     pub fn need_custom_serde(&self) -> bool {
-        self.serde_as_array
+        self.params.is_some()
+    }
+
+    fn render_arbitrary_body_stdout(&self, out: &mut CodeWriter) {
+        writeln!(out, "        let _ = u;");
+        writeln!(out, "        Ok(Self)");
     }
 
-    fn render_impl_serialize_stdout(&self, name: &str) {
-        println!("impl Serialize for {name} {{");
-        println!(
+    fn render_impl_serialize_stdout(&self, out: &mut CodeWriter, name: &str) {
+        writeln!(out, "impl Serialize for {name} {{");
+        writeln!(
+            out,
             "    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
         );
 
-        println!("        use serde::ser::SerializeSeq;");
-        println!();
-        println!("        let seq = serializer.serialize_seq(Some(0))?;");
-        println!("        seq.end()");
+        if self.params != Some(ParamStructure::ByName) {
+            writeln!(out, "        use serde::ser::SerializeSeq;");
+            writeln!(out);
+            writeln!(out, "        let seq = serializer.serialize_seq(Some(0))?;");
+            writeln!(out, "        seq.end()");
+        } else {
+            writeln!(out, "        use serde::ser::SerializeMap;");
+            writeln!(out);
+            writeln!(out, "        let map = serializer.serialize_map(Some(0))?;");
+            writeln!(out, "        map.end()");
+        }
 
-        println!("    }}");
-        println!("}}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
     }
 
-    fn render_impl_deserialize_stdout(&self, name: &str) {
-        println!("impl<'de> Deserialize<'de> for {name} {{");
-        println!("    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{");
+    fn render_impl_deserialize_stdout(&self, out: &mut CodeWriter, name: &str) {
+        writeln!(out, "impl<'de> Deserialize<'de> for {name} {{");
+        writeln!(out, "    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{");
 
-        println!("        let elements = Vec::<()>::deserialize(deserializer)?;");
-        println!("        if !elements.is_empty() {{");
-        println!("            return Err(serde::de::Error::custom(\"invalid sequence length\"));");
-        println!("        }}");
-        println!("        Ok(Self)");
+        writeln!(
+            out,
+            "        match serde_json::Value::deserialize(deserializer)? {{"
+        );
+        writeln!(
+            out,
+            "            serde_json::Value::Array(elements) if elements.is_empty() => Ok(Self),"
+        );
+        writeln!(
+            out,
+            "            serde_json::Value::Object(fields) if fields.is_empty() => Ok(Self),"
+        );
+        writeln!(
+            out,
+            "            _ => Err(serde::de::Error::custom(\"invalid params\")),"
+        );
+        writeln!(out, "        }}");
 
-        println!("    }}");
-        println!("}}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
     }
 }
 
-impl RustField {
-    pub fn def_lines(
+impl RustOneOf {
+    pub fn render_stdout(
         &self,
-        leading_spaces: usize,
-        serde_attrs: bool,
-        is_ref: bool,
-        no_arc_wrapping: bool,
-    ) -> Vec<String> {
-        let mut lines = vec![];
+        out: &mut CodeWriter,
+        name: &str,
+        derives: &[String],
+        attributes: &[String],
+    ) {
+        print_derives(out, derives);
+        writeln!(out, "#[serde(untagged)]");
+        print_attributes(out, attributes);
+        writeln!(out, "pub enum {name} {{");
 
-        let leading_spaces = " ".repeat(leading_spaces);
-
-        if serde_attrs {
-            if self.optional {
-                lines.push(format!(
-                    "{leading_spaces}#[serde(skip_serializing_if = \"Option::is_none\")]"
-                ));
-            }
-            if let Some(serde_rename) = &self.serde_rename {
-                lines.push(format!(
-                    "{leading_spaces}#[serde(rename = \"{serde_rename}\")]"
-                ));
+        for variant in self.variants.iter() {
+            if let Some(doc) = &variant.description {
+                print_doc(out, doc, 4);
             }
-            if self.serde_faltten {
-                lines.push(format!("{leading_spaces}#[serde(flatten)]"));
+            match variant.literal {
+                Some(literal) => {
+                    writeln!(out, "    #[serde(");
+                    writeln!(
+                        out,
+                        "        serialize_with = \"{name}::serialize_{literal}\","
+                    );
+                    writeln!(
+                        out,
+                        "        deserialize_with = \"{name}::deserialize_{literal}\""
+                    );
+                    writeln!(out, "    )]");
+                    writeln!(out, "    {},", variant.name);
+                }
+                None => writeln!(out, "    {}({}),", variant.name, variant.type_name),
             }
-            if let Some(serde_as) = &self.serializer {
+        }
+
+        writeln!(out, "}}");
+
+        if self.extra_ref_type {
+            self.render_ref_stdout(out, name, derives);
+        }
+        if self.extra_borrowed_type {
+            self.render_borrowed_stdout(out, name, derives);
+        }
+
+        let mut literals = self
+            .variants
+            .iter()
+            .filter_map(|variant| variant.literal)
+            .collect::<Vec<_>>();
+        literals.sort();
+        literals.dedup();
+        if literals.is_empty() {
+            return;
+        }
+
+        writeln!(out);
+        writeln!(out, "impl {name} {{");
+        for (ind, literal) in literals.into_iter().enumerate() {
+            if ind > 0 {
+                writeln!(out);
+            }
+            writeln!(
+                out,
+                "    fn serialize_{literal}<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {{"
+            );
+            writeln!(out, "        serializer.serialize_bool({literal})");
+            writeln!(out, "    }}");
+            writeln!(out);
+            writeln!(
+                out,
+                "    fn deserialize_{literal}<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {{"
+            );
+            writeln!(out, "        match bool::deserialize(deserializer)? {{");
+            writeln!(out, "            {literal} => Ok(()),");
+            writeln!(
+                out,
+                "            {} => Err(serde::de::Error::invalid_value(",
+                !literal
+            );
+            writeln!(
+                out,
+                "                serde::de::Unexpected::Bool({}),",
+                !literal
+            );
+            writeln!(out, "                &\"{literal}\",");
+            writeln!(out, "            )),");
+            writeln!(out, "        }}");
+            writeln!(out, "    }}");
+        }
+        writeln!(out, "}}");
+    }
+
+    /// Renders the `Ref` variant of the enum, serializing exactly like the owned type.
+    fn render_ref_stdout(&self, out: &mut CodeWriter, name: &str, derives: &[String]) {
+        let derive_serde = derives.iter().any(|item| item == "Serialize");
+
+        writeln!(out);
+        print_doc(out, &format!("Reference version of [{}].", name), 0);
+        if derive_serde {
+            writeln!(out, "#[derive(Debug, Clone, Serialize)]");
+            writeln!(out, "#[serde(untagged)]");
+        } else {
+            writeln!(out, "#[derive(Debug, Clone)]");
+        }
+        writeln!(out, "pub enum {name}Ref<'a> {{");
+
+        for variant in self.variants.iter() {
+            if let Some(doc) = &variant.description {
+                print_doc(out, doc, 4);
+            }
+            match variant.literal {
+                Some(literal) => {
+                    if derive_serde {
+                        writeln!(
+                            out,
+                            "    #[serde(serialize_with = \"{name}::serialize_{literal}\")]"
+                        );
+                    }
+                    writeln!(out, "    {},", variant.name);
+                }
+                None => writeln!(
+                    out,
+                    "    {}({}),",
+                    variant.name,
+                    variant
+                        .ref_type_name
+                        .as_deref()
+                        .unwrap_or(&variant.type_name)
+                ),
+            }
+        }
+
+        writeln!(out, "}}");
+    }
+
+    /// Renders the `Borrowed` variant of the enum, with the `Borrowed` variants of the types it
+    /// contains.
+    fn render_borrowed_stdout(&self, out: &mut CodeWriter, name: &str, derives: &[String]) {
+        let derive_serde = derives.iter().any(|item| item == "Deserialize");
+
+        writeln!(out);
+        print_doc(
+            out,
+            &format!(
+                "Zero-copy version of [{}] for deserialization, borrowing strings from the input \
+                and parsing field elements lazily.",
+                name
+            ),
+            0,
+        );
+        if derive_serde {
+            writeln!(out, "#[derive(Debug, Clone, Deserialize)]");
+            writeln!(out, "#[serde(untagged)]");
+        } else {
+            writeln!(out, "#[derive(Debug, Clone)]");
+        }
+        writeln!(out, "pub enum {name}Borrowed<'a> {{");
+
+        for variant in self.variants.iter() {
+            if let Some(doc) = &variant.description {
+                print_doc(out, doc, 4);
+            }
+            let type_name = variant
+                .borrowed_type_name
+                .as_deref()
+                .unwrap_or(&variant.type_name);
+            match variant.literal {
+                Some(literal) => {
+                    if derive_serde {
+                        writeln!(
+                            out,
+                            "    #[serde(deserialize_with = \"{name}::deserialize_{literal}\")]"
+                        );
+                    }
+                    writeln!(out, "    {},", variant.name);
+                }
+                None => {
+                    if derive_serde && type_name.contains("'a") {
+                        writeln!(out, "    #[serde(borrow)]");
+                    }
+                    writeln!(out, "    {}({}),", variant.name, type_name);
+                }
+            }
+        }
+
+        writeln!(out, "}}");
+    }
+
+    pub fn default_derives(&self) -> Vec<&'static str> {
+        vec!["Debug", "Clone", "Serialize", "Deserialize"]
+    }
+
+    pub fn need_custom_serde(&self) -> bool {
+        false
+    }
+
+    fn render_arbitrary_body_stdout(&self, out: &mut CodeWriter) {
+        writeln!(
+            out,
+            "        Ok(match u.int_in_range(0..={})? {{",
+            self.variants.len() - 1
+        );
+
+        for (ind, variant) in self.variants.iter().enumerate() {
+            let value = match (variant.literal, variant.legal_value) {
+                (Some(_), _) => format!("Self::{}", variant.name),
+                (None, Some(legal_value)) => format!("Self::{}({})", variant.name, legal_value),
+                (None, None) => format!(
+                    "Self::{}({})",
+                    variant.name,
+                    to_arbitrary_expr(&variant.type_name, "u.arbitrary()?")
+                ),
+            };
+            if ind == self.variants.len() - 1 {
+                writeln!(out, "            _ => {},", value);
+            } else {
+                writeln!(out, "            {} => {},", ind, value);
+            }
+        }
+
+        writeln!(out, "        }})");
+    }
+}
+
+impl OneOfConversion {
+    pub fn render_stdout(&self, out: &mut CodeWriter) {
+        let mut expr = String::from("value");
+        for (enum_name, variant_name) in self.path.iter().rev() {
+            expr = format!("{enum_name}::{variant_name}({expr})");
+        }
+        let pattern = expr.clone();
+        let expr = expr.replacen(&format!("{}::", self.enum_name), "Self::", 1);
+
+        writeln!(
+            out,
+            "impl From<{}> for {} {{",
+            self.type_name, self.enum_name
+        );
+        writeln!(out, "    fn from(value: {}) -> Self {{", self.type_name);
+        writeln!(out, "        {expr}");
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+        writeln!(out);
+        print_doc(
+            out,
+            &format!(
+                "Unwraps a [{}] value, giving the enum back as the error if it holds another \
+                variant.",
+                self.type_name
+            ),
+            0,
+        );
+        writeln!(
+            out,
+            "impl TryFrom<{}> for {} {{",
+            self.enum_name, self.type_name
+        );
+        writeln!(out, "    type Error = {};", self.enum_name);
+        writeln!(out);
+        writeln!(
+            out,
+            "    fn try_from(value: {}) -> Result<Self, Self::Error> {{",
+            self.enum_name
+        );
+        if self.exhaustive {
+            writeln!(out, "        let {pattern} = value;");
+            writeln!(out, "        Ok(value)");
+        } else {
+            writeln!(out, "        match value {{");
+            print_match_arm(out, &pattern, "Ok(value)", 12);
+            writeln!(out, "            value => Err(value),");
+            writeln!(out, "        }}");
+        }
+        writeln!(out, "    }}");
+        writeln!(out, "}}");
+    }
+}
+
+impl RustField {
+    pub fn def_lines(
+        &self,
+        leading_spaces: usize,
+        serde_attrs: bool,
+        is_ref: bool,
+        no_arc_wrapping: bool,
+    ) -> Vec<String> {
+        let mut lines = vec![];
+
+        let leading_spaces = " ".repeat(leading_spaces);
+
+        if serde_attrs {
+            if self.optional {
+                let mut args = vec![];
+                if self.serde_default || self.nullable {
+                    args.push("default");
+                }
+                if !self.serialize_none {
+                    args.push("skip_serializing_if = \"Option::is_none\"");
+                }
+                if !args.is_empty() {
+                    lines.push(format!("{leading_spaces}#[serde({})]", args.join(", ")));
+                }
+            }
+            if self.optional && self.nullable {
+                lines.push(format!(
+                    "{leading_spaces}#[serde(deserialize_with = \"serde_with::rust::double_option::deserialize\")]"
+                ));
+            }
+            if let Some(serde_rename) = &self.serde_rename {
+                lines.push(format!(
+                    "{leading_spaces}#[serde(rename = \"{serde_rename}\")]"
+                ));
+            }
+            if self.serde_faltten {
+                lines.push(format!("{leading_spaces}#[serde(flatten)]"));
+            }
+            if let Some(serde_as) = self
+                .serializer
+                .as_ref()
+                .filter(|_| !(is_ref && self.nested_ref))
+            {
                 lines.push(match serde_as {
                     SerializerOverride::Serde(serializer) => {
                         format!("{leading_spaces}#[serde(with = \"{serializer}\")]")
@@ -764,13 +2336,7 @@ impl RustField {
             leading_spaces,
             escape_name(&self.name),
             if is_ref {
-                if self.type_name == "String" {
-                    String::from("&'a str")
-                } else if self.type_name.starts_with("Vec<") {
-                    format!("&'a [{}]", &self.type_name[4..(self.type_name.len() - 1)])
-                } else {
-                    format!("&'a {}", self.type_name)
-                }
+                self.ref_type_name(Some("'a"))
             } else if self.arc_wrap && !no_arc_wrapping {
                 format!("Arc<{}>", self.type_name)
             } else {
@@ -780,20 +2346,375 @@ impl RustField {
 
         lines
     }
+
+    /// Type of the field in the `Ref` variant of a request, borrowing for `lifetime`, or for an
+    /// elided lifetime if not set.
+    pub fn ref_type_name(&self, lifetime: Option<&str>) -> String {
+        let reference = match lifetime {
+            Some(lifetime) => format!("&{lifetime} "),
+            None => String::from("&"),
+        };
+
+        if self.nested_ref {
+            format!(
+                "{}{}",
+                reference,
+                self.nested_ref_type_name(lifetime.unwrap_or("'_"))
+            )
+        } else if self.type_name == "String" {
+            format!("{reference}str")
+        } else if self.type_name.starts_with("Vec<") {
+            format!(
+                "{}[{}]",
+                reference,
+                &self.type_name[4..(self.type_name.len() - 1)]
+            )
+        } else {
+            format!("{}{}", reference, self.type_name)
+        }
+    }
+
+    /// Field definition in the `Borrowed` variant of the parent type. Serializer overrides only
+    /// apply when the field keeps its owned type.
+    pub fn borrowed_def_lines(
+        &self,
+        leading_spaces: usize,
+        serde_attrs: bool,
+        as_optional: bool,
+    ) -> Vec<String> {
+        let mut lines = vec![];
+
+        let leading_spaces = " ".repeat(leading_spaces);
+        let type_name = self
+            .borrowed_type_name
+            .clone()
+            .unwrap_or_else(|| self.type_name.clone());
+        let type_name = if as_optional {
+            format!("Option<{type_name}>")
+        } else {
+            type_name
+        };
+
+        if serde_attrs {
+            if let Some(serde_rename) = &self.serde_rename {
+                lines.push(format!(
+                    "{leading_spaces}#[serde(rename = \"{serde_rename}\")]"
+                ));
+            }
+            if self.serde_faltten {
+                lines.push(format!("{leading_spaces}#[serde(flatten)]"));
+            }
+            if type_name.contains("'a") {
+                lines.push(format!("{leading_spaces}#[serde(borrow)]"));
+            }
+            let double_option = self.optional && self.nullable && !as_optional;
+            if let Some(adapter) = to_borrow_cow_adapter(&type_name).filter(|_| !double_option) {
+                lines.push(format!("{leading_spaces}#[serde_as(as = \"{adapter}\")]"));
+            }
+            if double_option {
+                lines.push(format!(
+                    "{leading_spaces}#[serde(default, \
+                    deserialize_with = \"serde_with::rust::double_option::deserialize\")]"
+                ));
+            } else if self.optional && self.serde_default && !as_optional {
+                lines.push(format!("{leading_spaces}#[serde(default)]"));
+            }
+            if let Some(serializer) = self
+                .serializer
+                .as_ref()
+                .filter(|_| !self.borrowed_changed())
+            {
+                let serializer = if as_optional {
+                    serializer
+                        .to_optional()
+                        .expect("Fixed field serializer cannot be made optional")
+                } else {
+                    serializer.to_owned()
+                };
+                lines.push(match serializer {
+                    SerializerOverride::Serde(serializer) => {
+                        format!("{leading_spaces}#[serde(with = \"{serializer}\")]")
+                    }
+                    SerializerOverride::SerdeAs(serializer) => {
+                        format!("{leading_spaces}#[serde_as(as = \"{serializer}\")]")
+                    }
+                });
+            }
+        }
+
+        lines.push(format!(
+            "{}pub {}: {},",
+            leading_spaces,
+            escape_name(&self.name),
+            type_name
+        ));
+
+        lines
+    }
+
+    /// Whether the field type differs in the `Borrowed` variant of the parent type.
+    fn borrowed_changed(&self) -> bool {
+        self.borrowed_type_name
+            .as_ref()
+            .is_some_and(|borrowed| borrowed != &self.type_name)
+    }
+
+    fn borrowed_serde_as(&self) -> bool {
+        if self.borrowed_changed() {
+            self.borrowed_type_name
+                .as_deref()
+                .is_some_and(|type_name| to_borrow_cow_adapter(type_name).is_some())
+        } else {
+            matches!(self.serializer, Some(SerializerOverride::SerdeAs(_)))
+        }
+    }
+
+    /// Type of the field in the `Ref` variant of a model type. Arrays are borrowed as slices of
+    /// the owned type even if it has a `Ref` variant, as the variants can't be borrowed from them.
+    pub fn model_ref_type_name(&self) -> String {
+        if self.nested_ref {
+            self.nested_ref_type_name("'a")
+        } else if let Some(borrowed) = self.borrowed_option_type_name() {
+            format!("Option<{borrowed}>")
+        } else if self.type_name == "String" {
+            String::from("Cow<'a, str>")
+        } else if let Some(inner) = strip_generic(&self.type_name, "Vec") {
+            format!("Cow<'a, [{inner}]>")
+        } else {
+            format!("&'a {}", self.type_name)
+        }
+    }
+
+    /// Type of an optional string or array inside `Option` in the `Ref` variant of a model type,
+    /// so that it can be set from borrowed data. `None` for other fields, and for fields whose
+    /// serializer only applies to the owned type.
+    fn borrowed_option_type_name(&self) -> Option<String> {
+        if self.nested_ref {
+            return None;
+        }
+
+        let inner = strip_generic(&self.type_name, "Option")?;
+        let borrowed = if inner == "String" {
+            String::from("&'a str")
+        } else {
+            format!("&'a [{}]", strip_generic(inner, "Vec")?)
+        };
+
+        match &self.serializer {
+            None => Some(borrowed),
+            Some(SerializerOverride::SerdeAs(serde_as))
+                if strip_generic(serde_as, "Option")
+                    .and_then(|inner| strip_generic(inner, "Vec"))
+                    .is_some() =>
+            {
+                Some(borrowed)
+            }
+            _ => None,
+        }
+    }
+
+    /// The field as serialized from the `Ref` variant of a model type, if its type there is an
+    /// `Option` of borrowed data rather than a reference to the owned field.
+    fn borrowed_option_field(&self) -> Option<RustField> {
+        self.borrowed_option_type_name()?;
+
+        Some(RustField {
+            type_name: self.model_ref_type_name(),
+            serializer: self.serializer.as_ref().map(|serializer| match serializer {
+                SerializerOverride::SerdeAs(serde_as) => SerializerOverride::SerdeAs(format!(
+                    "Option<&[{}]>",
+                    strip_generic(serde_as, "Option")
+                        .and_then(|inner| strip_generic(inner, "Vec"))
+                        .unwrap_or_default()
+                )),
+                other => other.clone(),
+            }),
+            ..self.clone()
+        })
+    }
+
+    /// `Ref` variant of the field type, kept inside `Option` for optional fields.
+    fn nested_ref_type_name(&self, lifetime: &str) -> String {
+        match strip_generic(&self.type_name, "Option") {
+            Some(inner) => format!("Option<{inner}Ref<{lifetime}>>"),
+            None => format!("{}Ref<{}>", self.type_name, lifetime),
+        }
+    }
+
+    /// Whether the field is optional and its type is `Option<T>`. Optional method params are not
+    /// wrapped in `Option`.
+    pub fn is_option(&self) -> bool {
+        self.optional && strip_generic(&self.type_name, "Option").is_some()
+    }
+
+    pub fn type_name_with_wrapping(&self) -> String {
+        if self.arc_wrap {
+            format!("Arc<{}>", self.type_name)
+        } else {
+            self.type_name.clone()
+        }
+    }
+
+    pub fn arbitrary_expr(&self) -> String {
+        let expr = to_arbitrary_expr(&self.type_name, "u.arbitrary()?");
+
+        if self.arc_wrap {
+            format!("Arc::new({expr})")
+        } else {
+            expr
+        }
+    }
+}
+
+impl AccessorTrait {
+    pub fn render_stdout(&self, out: &mut CodeWriter) {
+        print_doc(
+            out,
+            &format!(
+                "Accessors for the properties of `{}`, implemented by all types it's flattened \
+                into.",
+                self.schema_name
+            ),
+            0,
+        );
+        writeln!(out, "pub trait {} {{", self.name);
+        for (ind, field) in self.fields.iter().enumerate() {
+            if ind > 0 {
+                writeln!(out);
+            }
+            if let Some(doc) = &field.description {
+                print_doc(out, doc, 4);
+            }
+            writeln!(
+                out,
+                "    fn {}(&self) -> &{};",
+                escape_name(&field.name),
+                Self::return_type(field)
+            );
+        }
+        writeln!(out, "}}");
+
+        for implementor in self.implementors.iter() {
+            writeln!(out);
+            writeln!(out, "impl {} for {} {{", self.name, implementor);
+            for (ind, field) in self.fields.iter().enumerate() {
+                if ind > 0 {
+                    writeln!(out);
+                }
+                writeln!(
+                    out,
+                    "    fn {}(&self) -> &{} {{",
+                    escape_name(&field.name),
+                    Self::return_type(field)
+                );
+                if Self::slice_item_type(field).is_some() {
+                    writeln!(out, "        self.{}.as_slice()", escape_name(&field.name));
+                } else {
+                    writeln!(out, "        &self.{}", escape_name(&field.name));
+                }
+                writeln!(out, "    }}");
+            }
+            writeln!(out, "}}");
+        }
+
+        for (implementor, variants) in self.delegating_implementors.iter() {
+            writeln!(out);
+            writeln!(out, "impl {} for {} {{", self.name, implementor);
+            for (ind, field) in self.fields.iter().enumerate() {
+                if ind > 0 {
+                    writeln!(out);
+                }
+                writeln!(
+                    out,
+                    "    fn {}(&self) -> &{} {{",
+                    escape_name(&field.name),
+                    Self::return_type(field)
+                );
+                writeln!(out, "        match self {{");
+                for variant in variants.iter() {
+                    writeln!(
+                        out,
+                        "            Self::{}(value) => {}::{}(value),",
+                        variant,
+                        self.name,
+                        escape_name(&field.name)
+                    );
+                }
+                writeln!(out, "        }}");
+                writeln!(out, "    }}");
+            }
+            writeln!(out, "}}");
+        }
+    }
+
+    /// Type returned by reference from the accessor of `field`, with arrays returned as slices.
+    fn return_type(field: &RustField) -> String {
+        match Self::slice_item_type(field) {
+            Some(item_type) => format!("[{item_type}]"),
+            None => field.type_name.clone(),
+        }
+    }
+
+    fn slice_item_type(field: &RustField) -> Option<&str> {
+        field
+            .type_name
+            .strip_prefix("Vec<")
+            .and_then(|item_type| item_type.strip_suffix('>'))
+    }
 }
 
 impl SerializerOverride {
-    pub fn to_optional(&self) -> Self {
+    /// Field attribute applying the serializer.
+    pub fn attribute(&self) -> String {
         match self {
-            SerializerOverride::Serde(_) => {
-                todo!("Optional transformation of #[serde(with)] not implemented")
+            SerializerOverride::Serde(serializer) => format!("#[serde(with = \"{serializer}\")]"),
+            SerializerOverride::SerdeAs(serializer) => {
+                format!("#[serde_as(as = \"{serializer}\")]")
             }
-            SerializerOverride::SerdeAs(serde_as) => Self::SerdeAs(format!("Option<{serde_as}>")),
+        }
+    }
+
+    pub fn to_optional(&self) -> Result<Self> {
+        self.wrap("Option")
+    }
+
+    pub fn to_array(&self) -> Result<Self> {
+        self.wrap("Vec")
+    }
+
+    /// Wraps the serializer for use on `{wrapper}<T>`. `#[serde(with)]` modules are turned into
+    /// `serde_as` through their adapter types as `with` can't be composed.
+    fn wrap(&self, wrapper: &str) -> Result<Self> {
+        let serde_as = match self {
+            SerializerOverride::Serde(module) => match SERDE_WITH_ADAPTERS
+                .iter()
+                .find(|adapter| adapter.module == module)
+            {
+                Some(adapter) => adapter.adapter,
+                None => anyhow::bail!(
+                    "No `serde_as` adapter for `#[serde(with = \"{}\")]` inside `{}`",
+                    module,
+                    wrapper
+                ),
+            },
+            SerializerOverride::SerdeAs(serde_as) => serde_as,
+        };
+
+        Ok(Self::SerdeAs(format!("{wrapper}<{serde_as}>")))
+    }
+
+    /// Whether the serializer relies on the adapter of a `#[serde(with)]` module.
+    pub fn uses_adapter(&self, adapter: &SerdeWithAdapter) -> bool {
+        match self {
+            SerializerOverride::Serde(_) => false,
+            SerializerOverride::SerdeAs(serde_as) => serde_as
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|identifier| identifier == adapter.adapter),
         }
     }
 }
 
-fn main() {
+fn main() -> Result<()> {
     let cli = Cli::parse();
 
     let profiles: [GenerationProfile; 3] = [
@@ -814,6 +2735,19 @@ fn main() {
             arc_wrapped_types: ArcWrappingOptions {
                 arc_wrapped_types: vec![],
             },
+            derive_options: DeriveOptions {
+                derives: vec![],
+                removed_derives: vec![],
+                attributes: vec![],
+                types: vec![],
+            },
+            ref_types: vec![],
+            borrowed_types: vec![],
+            optional_field_options: OptionalFieldOptions {
+                none_serialization: NoneSerialization::Omit,
+                serde_default: false,
+                fields: vec![],
+            },
         },
         GenerationProfile {
             version: SpecVersion::V0_2_1,
@@ -821,24 +2755,18 @@ fn main() {
                 main: include_str!("./specs/0.2.1/starknet_api_openrpc.json"),
                 write: include_str!("./specs/0.2.1/starknet_write_api.json"),
             },
-            flatten_options: FlattenOption::Selected(vec![
-                String::from("FUNCTION_CALL"),
-                String::from("EVENT"),
-                String::from("TYPED_PARAMETER"),
-                String::from("BLOCK_BODY_WITH_TXS"),
-                String::from("BLOCK_BODY_WITH_TX_HASHES"),
-                String::from("BLOCK_HEADER"),
-                String::from("BROADCASTED_TXN_COMMON_PROPERTIES"),
-                String::from("DEPLOY_ACCOUNT_TXN_PROPERTIES"),
-                String::from("DEPLOY_TXN_PROPERTIES"),
-                String::from("EVENT_CONTENT"),
-                String::from("PENDING_COMMON_RECEIPT_PROPERTIES"),
-                String::from("COMMON_TXN_PROPERTIES"),
-                String::from("COMMON_RECEIPT_PROPERTIES"),
-            ]),
+            flatten_options: FlattenOption::Inferred {
+                flatten: vec![
+                    String::from("FUNCTION_CALL"),
+                    String::from("EVENT"),
+                    String::from("TYPED_PARAMETER"),
+                ],
+                no_flatten: vec![
+                    String::from("EVENT_FILTER"),
+                    String::from("RESULT_PAGE_REQUEST"),
+                ],
+            },
             ignore_types: vec![],
-            // We need these because they're implied by the network but not explicitly specified.
-            // So it's impossible to dynamically derive them accurately.
             fixed_field_types: FixedFieldsOptions {
                 fixed_field_types: vec![
                     RustTypeWithFixedFields {
@@ -985,13 +2913,6 @@ fn main() {
                             },
                         ],
                     },
-                    RustTypeWithFixedFields {
-                        name: "L1HandlerTransaction",
-                        fields: vec![FixedField {
-                            name: "type",
-                            value: "\"L1_HANDLER\"",
-                        }],
-                    },
                     RustTypeWithFixedFields {
                         name: "InvokeTransactionReceipt",
                         fields: vec![FixedField {
@@ -1076,6 +2997,19 @@ fn main() {
                     },
                 ],
             },
+            derive_options: DeriveOptions {
+                derives: vec![],
+                removed_derives: vec![],
+                attributes: vec![],
+                types: vec![],
+            },
+            ref_types: vec![],
+            borrowed_types: vec![],
+            optional_field_options: OptionalFieldOptions {
+                none_serialization: NoneSerialization::Omit,
+                serde_default: false,
+                fields: vec![],
+            },
         },
         GenerationProfile {
             version: SpecVersion::V0_3_0,
@@ -1083,205 +3017,90 @@ fn main() {
                 main: include_str!("./specs/0.3.0/starknet_api_openrpc.json"),
                 write: include_str!("./specs/0.3.0/starknet_write_api.json"),
             },
-            flatten_options: FlattenOption::Selected(vec![
-                String::from("FUNCTION_CALL"),
-                String::from("EVENT"),
-                String::from("TYPED_PARAMETER"),
-                String::from("BLOCK_BODY_WITH_TXS"),
-                String::from("BLOCK_BODY_WITH_TX_HASHES"),
-                String::from("BLOCK_HEADER"),
-                String::from("BROADCASTED_TXN_COMMON_PROPERTIES"),
-                String::from("DEPLOY_ACCOUNT_TXN_PROPERTIES"),
-                String::from("DEPLOY_TXN_PROPERTIES"),
-                String::from("EVENT_CONTENT"),
-                String::from("PENDING_COMMON_RECEIPT_PROPERTIES"),
-                String::from("COMMON_TXN_PROPERTIES"),
-                String::from("COMMON_RECEIPT_PROPERTIES"),
-                String::from("PENDING_STATE_UPDATE"),
-                String::from("DECLARE_TXN_V1"),
-            ]),
+            flatten_options: FlattenOption::Inferred {
+                flatten: vec![
+                    String::from("FUNCTION_CALL"),
+                    String::from("EVENT"),
+                    String::from("TYPED_PARAMETER"),
+                    String::from("PENDING_STATE_UPDATE"),
+                    String::from("DECLARE_TXN_V1"),
+                ],
+                no_flatten: vec![
+                    String::from("EVENT_FILTER"),
+                    String::from("RESULT_PAGE_REQUEST"),
+                ],
+            },
             ignore_types: vec![],
-            // We need these because they're implied by the network but not explicitly specified.
-            // So it's impossible to dynamically derive them accurately.
             fixed_field_types: FixedFieldsOptions {
                 fixed_field_types: vec![
                     RustTypeWithFixedFields {
                         name: "DeclareTransactionV1",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"DECLARE\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&1",
-                            },
-                        ],
+                        fields: vec![FixedField {
+                            name: "version",
+                            value: "&1",
+                        }],
                     },
                     RustTypeWithFixedFields {
                         name: "DeclareTransactionV2",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"DECLARE\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&2",
-                            },
-                        ],
+                        fields: vec![FixedField {
+                            name: "version",
+                            value: "&2",
+                        }],
                     },
                     RustTypeWithFixedFields {
                         name: "BroadcastedDeclareTransactionV1",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"DECLARE\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&1",
-                            },
-                        ],
+                        fields: vec![FixedField {
+                            name: "version",
+                            value: "&1",
+                        }],
                     },
                     RustTypeWithFixedFields {
                         name: "BroadcastedDeclareTransactionV2",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"DECLARE\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&2",
-                            },
-                        ],
-                    },
-                    RustTypeWithFixedFields {
-                        name: "DeployAccountTransaction",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"DEPLOY_ACCOUNT\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&1",
-                            },
-                        ],
-                    },
-                    RustTypeWithFixedFields {
-                        name: "BroadcastedDeployAccountTransaction",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"DEPLOY_ACCOUNT\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&1",
-                            },
-                        ],
-                    },
-                    RustTypeWithFixedFields {
-                        name: "DeployTransaction",
                         fields: vec![FixedField {
-                            name: "type",
-                            value: "\"DEPLOY\"",
+                            name: "version",
+                            value: "&2",
                         }],
                     },
                     RustTypeWithFixedFields {
-                        name: "InvokeTransactionV0",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"INVOKE\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&0",
-                            },
-                        ],
-                    },
-                    RustTypeWithFixedFields {
-                        name: "InvokeTransactionV1",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"INVOKE\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&1",
-                            },
-                        ],
-                    },
-                    RustTypeWithFixedFields {
-                        name: "BroadcastedInvokeTransactionV0",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"INVOKE\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&0",
-                            },
-                        ],
-                    },
-                    RustTypeWithFixedFields {
-                        name: "BroadcastedInvokeTransactionV1",
-                        fields: vec![
-                            FixedField {
-                                name: "type",
-                                value: "\"INVOKE\"",
-                            },
-                            FixedField {
-                                name: "version",
-                                value: "&1",
-                            },
-                        ],
-                    },
-                    RustTypeWithFixedFields {
-                        name: "L1HandlerTransaction",
+                        name: "DeployAccountTransaction",
                         fields: vec![FixedField {
-                            name: "type",
-                            value: "\"L1_HANDLER\"",
+                            name: "version",
+                            value: "&1",
                         }],
                     },
                     RustTypeWithFixedFields {
-                        name: "InvokeTransactionReceipt",
+                        name: "BroadcastedDeployAccountTransaction",
                         fields: vec![FixedField {
-                            name: "type",
-                            value: "\"INVOKE\"",
+                            name: "version",
+                            value: "&1",
                         }],
                     },
                     RustTypeWithFixedFields {
-                        name: "DeclareTransactionReceipt",
+                        name: "InvokeTransactionV0",
                         fields: vec![FixedField {
-                            name: "type",
-                            value: "\"DECLARE\"",
+                            name: "version",
+                            value: "&0",
                         }],
                     },
                     RustTypeWithFixedFields {
-                        name: "DeployAccountTransactionReceipt",
+                        name: "InvokeTransactionV1",
                         fields: vec![FixedField {
-                            name: "type",
-                            value: "\"DEPLOY_ACCOUNT\"",
+                            name: "version",
+                            value: "&1",
                         }],
                     },
                     RustTypeWithFixedFields {
-                        name: "DeployTransactionReceipt",
+                        name: "BroadcastedInvokeTransactionV0",
                         fields: vec![FixedField {
-                            name: "type",
-                            value: "\"DEPLOY\"",
+                            name: "version",
+                            value: "&0",
                         }],
                     },
                     RustTypeWithFixedFields {
-                        name: "L1HandlerTransactionReceipt",
+                        name: "BroadcastedInvokeTransactionV1",
                         fields: vec![FixedField {
-                            name: "type",
-                            value: "\"L1_HANDLER\"",
+                            name: "version",
+                            value: "&1",
                         }],
                     },
                     RustTypeWithFixedFields {
@@ -1333,33 +3152,85 @@ fn main() {
                     },
                 ],
             },
+            derive_options: DeriveOptions {
+                derives: vec![],
+                removed_derives: vec![],
+                attributes: vec![],
+                types: vec![],
+            },
+            ref_types: vec![],
+            borrowed_types: vec![],
+            optional_field_options: OptionalFieldOptions {
+                none_serialization: NoneSerialization::Omit,
+                serde_default: false,
+                fields: vec![],
+            },
         },
     ];
 
-    let profile = profiles
+    let versions = if cli.all_specs {
+        SpecVersion::value_variants().to_vec()
+    } else {
+        let mut versions = vec![];
+        for version in cli.spec.iter() {
+            if !versions.contains(version) {
+                versions.push(*version);
+            }
+        }
+        versions
+    };
+
+    if cli.command.is_some() && versions.len() != 1 {
+        anyhow::bail!("Subcommands only support a single spec version");
+    }
+    if cli.version_conversions && versions.len() < 2 {
+        anyhow::bail!("Version conversions require more than one spec version");
+    }
+    if cli.spec_file.is_some() && versions.len() != 1 {
+        anyhow::bail!("A spec file can only replace a single spec version");
+    }
+    let document = match &cli.spec_file {
+        Some(path) => Some(
+            std::fs::read_to_string(path)
+                .map_err(|err| anyhow::anyhow!("Failed to read {}: {}", path.display(), err))?,
+        ),
+        None => None,
+    };
+
+    let mut resolved_profiles = vec![];
+    for mut profile in profiles
         .into_iter()
-        .find(|profile| profile.version == cli.spec)
-        .expect("Unable to find profile");
+        .filter(|profile| versions.contains(&profile.version))
+    {
+        apply_cli_options(&mut profile, &cli);
 
-    let mut specs: Specification =
-        serde_json::from_str(profile.raw_specs.main).expect("Failed to parse specification");
+        let mut specs = load_specs(&mut profile, document.as_deref(), cli.report_flatten);
 
-    // Merge specs (we only care about write methods and errors at the moment as the write specs
-    // does not provide additional models).
-    let mut write_specs: Specification =
-        serde_json::from_str(profile.raw_specs.write).expect("Failed to parse specification");
-    specs.methods.append(&mut write_specs.methods);
-    write_specs
-        .components
-        .errors
-        .iter()
-        .for_each(|(key, value)| {
-            if let indexmap::map::Entry::Vacant(entry) =
-                specs.components.errors.entry(key.to_owned())
-            {
-                entry.insert(value.to_owned());
+        if let Some(Command::Graph {
+            format,
+            dependents_of,
+        }) = &cli.command
+        {
+            let mut graph =
+                SchemaGraph::build(&specs, |name| profile.flatten_options.should_flatten(name));
+            if let Some(name) = dependents_of {
+                graph = graph
+                    .dependents_of(name)
+                    .expect("Failed to find dependents");
             }
-        });
+
+            graph.render_stdout(*format);
+            return Ok(());
+        }
+
+        // Flatten options are resolved on the full spec so that filtering doesn't change the types
+        // generated.
+        if !cli.methods.is_empty() {
+            filter_methods(&mut specs, &cli.methods).expect("Failed to filter methods");
+        }
+
+        resolved_profiles.push(resolve_profile(&cli, profile, specs));
+    }
 
     println!("// AUTO-GENERATED CODE. DO NOT EDIT");
     println!("// To change the code generated, modify the codegen tool instead:");
@@ -1374,96 +3245,685 @@ fn main() {
     }
     println!();
 
-    if !profile.ignore_types.is_empty() {
-        println!("// These types are ignored from code generation. Implement them manually:");
-        for ignored_type in profile.ignore_types.iter() {
-            println!("// - `{ignored_type}`");
-        }
-        println!();
+    if let [resolved] = resolved_profiles.as_slice() {
+        let mut out = CodeWriter::new(0);
+        print_module(&mut out, &cli, resolved, OutputModule::Single, &[]);
+        out.print();
+        return Ok(());
     }
 
-    let result = resolve_types(
-        &specs,
-        &profile.flatten_options,
-        &profile.ignore_types,
-        &profile.fixed_field_types,
-        &profile.arc_wrapped_types,
-    )
-    .expect("Failed to resolve types");
+    let shared_types = resolve_shared_types(&resolved_profiles);
+
+    print_wrapped_module(
+        "Types generated identically for all spec versions, re-exported by each version module.",
+        "shared",
+        |out| {
+            print_module(
+                out,
+                &cli,
+                resolved_profiles.last().expect("no profile resolved"),
+                OutputModule::Shared,
+                &shared_types,
+            )
+        },
+    );
 
-    if !result.not_implemented.is_empty() {
-        println!("// Code generation requested but not implemented for these types:");
-        for type_name in result.not_implemented.iter() {
-            println!("// - `{type_name}`");
-        }
+    for resolved in resolved_profiles.iter() {
         println!();
+        print_wrapped_module(
+            &format!(
+                "Types for version `{}` of the specification.",
+                resolved.profile.version.as_str()
+            ),
+            resolved.profile.version.module_name(),
+            |out| print_module(out, &cli, resolved, OutputModule::Versioned, &shared_types),
+        );
     }
 
-    println!("use std::sync::Arc;");
-    println!();
-
-    println!("use serde::{{Deserialize, Deserializer, Serialize, Serializer}};");
-    println!("use serde_with::serde_as;");
+    if cli.version_conversions {
+        let conversions = resolved_profiles
+            .windows(2)
+            .flat_map(|pair| {
+                [
+                    VersionConversions::resolve(&pair[0], &pair[1], &shared_types),
+                    VersionConversions::resolve(&pair[1], &pair[0], &shared_types),
+                ]
+            })
+            .collect::<Vec<_>>();
 
-    if profile.version == SpecVersion::V0_1_0 {
-        println!("use starknet_core::{{");
-        println!("    serde::{{byte_array::base64, unsigned_field_element::UfeHex}},");
-        println!("    types::FieldElement,");
-        println!("}};");
-    } else {
         println!();
-        println!("use crate::{{");
-        println!("    serde::{{byte_array::base64, unsigned_field_element::UfeHex}},");
-        println!("    types::FieldElement,");
-        println!("}};");
+        print_wrapped_module(
+            "Conversions between the same-named types of adjacent spec versions.",
+            "conversions",
+            |out| {
+                for item in conversions.iter() {
+                    item.render_report_stdout(out);
+                }
+                writeln!(
+                    out,
+                    "use super::{{{}}};",
+                    resolved_profiles
+                        .iter()
+                        .map(|resolved| resolved.profile.version.module_name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                writeln!(out);
+                print_version_conversion_error(out);
+                for item in conversions.iter() {
+                    writeln!(out);
+                    item.render_stdout(out);
+                }
+            },
+        );
     }
 
-    println!();
+    if cli.version_negotiation {
+        let negotiation = VersionNegotiation::resolve(
+            &resolved_profiles
+                .iter()
+                .map(|resolved| (resolved.profile.version.module_name(), &resolved.specs))
+                .collect::<Vec<_>>(),
+            &resolved_profiles
+                .iter()
+                .flat_map(|resolved| get_write_method_names(&resolved.profile))
+                .collect::<Vec<_>>(),
+        );
 
-    // In later versions this type is still defined by never actually used
-    if profile.version == SpecVersion::V0_1_0 {
-        println!("pub use starknet_core::types::L1Address as EthAddress;");
         println!();
+        print_wrapped_module(
+            "Detection of the spec version implemented by a node.",
+            "negotiation",
+            |out| negotiation.render_stdout(out),
+        );
     }
 
-    println!("use super::{{serde_impls::NumAsHex, *}};");
-    println!();
-
-    let mut manual_serde_types = vec![];
+    Ok(())
+}
 
-    for rust_type in result
-        .model_types
-        .iter()
-        .chain(result.request_response_types.iter())
-    {
-        if rust_type.need_custom_serde() {
-            manual_serde_types.push(rust_type);
-        }
+/// Prints a `pub mod` block, with the code rendered into it indented once.
+fn print_wrapped_module(doc: &str, name: &str, render: impl FnOnce(&mut CodeWriter)) {
+    let mut out = CodeWriter::new(4);
+    render(&mut out);
 
-        rust_type.render_stdout();
+    println!("/// {doc}");
+    println!("pub mod {name} {{");
+    out.print();
+    println!("}}");
+}
 
-        println!();
+/// Applies the options given on the command line on top of the profile defaults.
+fn apply_cli_options(profile: &mut GenerationProfile, cli: &Cli) {
+    profile.ref_types.extend(cli.ref_type.iter().cloned());
+    profile
+        .borrowed_types
+        .extend(cli.borrowed_type.iter().cloned());
+    if let Some(none_serialization) = cli.none_serialization {
+        profile.optional_field_options.none_serialization = none_serialization;
     }
-
-    for (ind, rust_type) in manual_serde_types.iter().enumerate() {
-        rust_type.render_serde_stdout();
-
-        if ind != manual_serde_types.len() - 1 {
-            println!();
-        }
+    profile.optional_field_options.serde_default |= cli.serde_default;
+    for (field_path, policy) in cli.field_none_serialization.iter() {
+        let (type_name, field_name) = parse_field_path(field_path).expect("Invalid optional field");
+        profile
+            .optional_field_options
+            .fields
+            .push(OptionalFieldOverride {
+                type_name,
+                field_name,
+                none_serialization: Some(
+                    policy
+                        .parse()
+                        .expect("Invalid optional field `None` serialization"),
+                ),
+                serde_default: None,
+            });
     }
-}
-
-fn resolve_types(
-    specs: &Specification,
-    flatten_option: &FlattenOption,
-    ignore_types: &[String],
-    fixed_fields: &FixedFieldsOptions,
+    for (field_path, serde_default) in cli.field_serde_default.iter() {
+        let (type_name, field_name) = parse_field_path(field_path).expect("Invalid optional field");
+        profile
+            .optional_field_options
+            .fields
+            .push(OptionalFieldOverride {
+                type_name,
+                field_name,
+                none_serialization: None,
+                serde_default: Some(
+                    serde_default
+                        .parse()
+                        .expect("Invalid optional field `#[serde(default)]` setting"),
+                ),
+            });
+    }
+    profile
+        .derive_options
+        .derives
+        .extend(cli.derive.iter().cloned());
+    profile
+        .derive_options
+        .attributes
+        .extend(cli.attribute.iter().cloned());
+    profile
+        .derive_options
+        .removed_derives
+        .extend(cli.no_derive.iter().cloned());
+    for (type_name, derives) in cli.type_derive.iter() {
+        profile.derive_options.types.push(RustTypeWithDerives {
+            name: type_name.to_owned(),
+            derives: split_traits(derives),
+            removed_derives: vec![],
+            attributes: vec![],
+            default_variant: None,
+        });
+    }
+    for (type_name, derives) in cli.type_no_derive.iter() {
+        profile.derive_options.types.push(RustTypeWithDerives {
+            name: type_name.to_owned(),
+            derives: vec![],
+            removed_derives: split_traits(derives),
+            attributes: vec![],
+            default_variant: None,
+        });
+    }
+    for (type_name, attribute) in cli.type_attribute.iter() {
+        profile.derive_options.types.push(RustTypeWithDerives {
+            name: type_name.to_owned(),
+            derives: vec![],
+            removed_derives: vec![],
+            attributes: vec![attribute.to_owned()],
+            default_variant: None,
+        });
+    }
+    for (type_name, variant) in cli.default_variant.iter() {
+        profile.derive_options.types.push(RustTypeWithDerives {
+            name: type_name.to_owned(),
+            derives: vec![],
+            removed_derives: vec![],
+            attributes: vec![],
+            default_variant: Some(variant.to_owned()),
+        });
+    }
+}
+
+/// Parses and merges the specifications of a profile, resolving its flatten options. A custom
+/// `document` replaces the specifications of the profile.
+fn load_specs(
+    profile: &mut GenerationProfile,
+    document: Option<&str>,
+    report_flatten: bool,
+) -> Specification {
+    let specs = match document {
+        Some(document) => serde_json::from_str(document).expect("Failed to parse specification"),
+        None => {
+            let mut specs: Specification = serde_json::from_str(profile.raw_specs.main)
+                .expect("Failed to parse specification");
+
+            // Merge specs (we only care about write methods and errors at the moment as the write
+            // specs does not provide additional models).
+            let mut write_specs: Specification = serde_json::from_str(profile.raw_specs.write)
+                .expect("Failed to parse specification");
+            specs.methods.append(&mut write_specs.methods);
+            write_specs
+                .components
+                .errors
+                .iter()
+                .for_each(|(key, value)| {
+                    if let indexmap::map::Entry::Vacant(entry) =
+                        specs.components.errors.entry(key.to_owned())
+                    {
+                        entry.insert(value.to_owned());
+                    }
+                });
+
+            specs
+        }
+    };
+
+    profile.flatten_options = profile.flatten_options.resolve(&specs, report_flatten);
+
+    specs
+}
+
+/// Names of the methods defined in the write specs of `profile`.
+fn get_write_method_names(profile: &GenerationProfile) -> Vec<String> {
+    let write_specs: Specification =
+        serde_json::from_str(profile.raw_specs.write).expect("Failed to parse specification");
+
+    write_specs
+        .methods
+        .into_iter()
+        .map(|method| method.name)
+        .collect()
+}
+
+fn resolve_profile(cli: &Cli, profile: GenerationProfile, specs: Specification) -> ResolvedProfile {
+    let mut result = resolve_types(
+        &specs,
+        &profile.flatten_options,
+        &profile.ignore_types,
+        &profile.fixed_field_types,
+        &profile.arc_wrapped_types,
+        &profile.derive_options,
+    )
+    .expect("Failed to resolve types");
+    if cli.report_fixed_fields {
+        for line in result.fixed_field_report.iter() {
+            eprintln!("{line}");
+        }
+    }
+    if cli.oneof_enums {
+        resolve_one_of_enums(&specs, &mut result, &profile.derive_options)
+            .expect("Failed to resolve oneOf enums");
+    } else {
+        for type_name in result.not_implemented.iter() {
+            eprintln!("OneOf enum generation not implemented. Enum not generated for {type_name}");
+        }
+    }
+    if cli.response_types {
+        resolve_response_types(
+            &specs,
+            &mut result,
+            &profile.flatten_options,
+            &profile.derive_options,
+        )
+        .expect("Failed to resolve response types");
+    }
+    if cli.arbitrary {
+        check_arbitrary_patterns(&specs, &profile.ignore_types)
+            .expect("Failed to resolve arbitrary implementations");
+    }
+    resolve_default_derives(&mut result, &profile.derive_options)
+        .expect("Failed to resolve default derives");
+    check_derives(
+        result
+            .model_types
+            .iter()
+            .chain(result.request_response_types.iter()),
+    )
+    .expect("Failed to resolve types");
+    resolve_optional_fields(&mut result, &profile.optional_field_options)
+        .expect("Failed to resolve optional fields");
+    resolve_ref_types(&mut result.model_types, &profile.ref_types)
+        .expect("Failed to resolve ref types");
+    resolve_borrowed_types(&mut result.model_types, &profile.borrowed_types)
+        .expect("Failed to resolve borrowed types");
+
+    ResolvedProfile {
+        profile,
+        specs,
+        result,
+    }
+}
+
+/// Finds the types generated identically, ignoring docs, for all resolved profiles. Types
+/// referencing anything that's not shared are never shared themselves, so that the shared module
+/// doesn't depend on any version module.
+fn resolve_shared_types(profiles: &[ResolvedProfile]) -> Vec<String> {
+    let (last, others) = profiles.split_last().expect("no profile resolved");
+
+    let mut shared_types = last
+        .result
+        .all_types()
+        .filter(|rust_type| {
+            // Error codes are implemented for each version by generated clients
+            rust_type.name != "StarknetError"
+                && others.iter().all(|other| {
+                    other
+                        .result
+                        .all_types()
+                        .any(|item| item.is_structurally_equal(rust_type))
+                })
+        })
+        .map(|rust_type| rust_type.name.clone())
+        .collect::<Vec<_>>();
+
+    loop {
+        let mut version_specific = HashSet::new();
+        for resolved in profiles.iter() {
+            let names = resolved
+                .result
+                .all_types()
+                .map(|rust_type| rust_type.name.clone())
+                .chain(
+                    resolved
+                        .result
+                        .not_implemented
+                        .iter()
+                        .chain(resolved.profile.ignore_types.iter())
+                        .map(|name| to_starknet_rs_name(name)),
+                )
+                .filter(|name| !shared_types.contains(name));
+            for name in names {
+                version_specific.insert(format!("{name}Ref"));
+                version_specific.insert(format!("{name}Borrowed"));
+                version_specific.insert(name);
+            }
+        }
+
+        let shared_count = shared_types.len();
+        shared_types.retain(|name| {
+            last.result
+                .all_types()
+                .find(|rust_type| &rust_type.name == name)
+                .expect("shared type not found")
+                .referenced_type_names()
+                .iter()
+                .all(|referenced| !version_specific.contains(referenced))
+        });
+
+        if shared_types.len() == shared_count {
+            break;
+        }
+    }
+
+    shared_types
+}
+
+/// Prints the generated code of a resolved profile. Outside of [OutputModule::Single], only
+/// `shared_types` are generated in [OutputModule::Shared], and all other types in
+/// [OutputModule::Versioned], with the shared ones re-exported.
+fn print_module(
+    out: &mut CodeWriter,
+    cli: &Cli,
+    resolved: &ResolvedProfile,
+    module: OutputModule,
+    shared_types: &[String],
+) {
+    let ResolvedProfile {
+        profile,
+        specs,
+        result,
+    } = resolved;
+
+    let owned_types = result
+        .all_types()
+        .filter(|rust_type| match module {
+            OutputModule::Single => true,
+            OutputModule::Shared => shared_types.contains(&rust_type.name),
+            OutputModule::Versioned => !shared_types.contains(&rust_type.name),
+        })
+        .collect::<Vec<_>>();
+    let owns_builder_error = cli.builders && module != OutputModule::Versioned;
+    let owns_lazy_field_element =
+        !profile.borrowed_types.is_empty() && module != OutputModule::Versioned;
+
+    if module != OutputModule::Shared {
+        if !cli.methods.is_empty() {
+            writeln!(out, "// Only types used by these methods are generated:");
+            for method in cli.methods.iter() {
+                writeln!(out, "// - `{method}`");
+            }
+            writeln!(out);
+        }
+
+        if !profile.ignore_types.is_empty() {
+            writeln!(
+                out,
+                "// These types are ignored from code generation. Implement them manually:"
+            );
+            for ignored_type in profile.ignore_types.iter() {
+                writeln!(out, "// - `{ignored_type}`");
+            }
+            writeln!(out);
+        }
+
+        if !result.not_implemented.is_empty() {
+            writeln!(
+                out,
+                "// Code generation requested but not implemented for these types:"
+            );
+            for type_name in result.not_implemented.iter() {
+                writeln!(out, "// - `{type_name}`");
+            }
+            writeln!(out);
+        }
+    }
+
+    if profile.ref_types.is_empty() && profile.borrowed_types.is_empty() {
+        writeln!(out, "use std::sync::Arc;");
+    } else {
+        writeln!(out, "use std::{{borrow::Cow, sync::Arc}};");
+    }
+    writeln!(out);
+
+    writeln!(
+        out,
+        "use serde::{{Deserialize, Deserializer, Serialize, Serializer}};"
+    );
+    writeln!(out, "use serde_with::serde_as;");
+
+    if profile.version == SpecVersion::V0_1_0 && module != OutputModule::Shared {
+        writeln!(out, "use starknet_core::{{");
+        writeln!(
+            out,
+            "    serde::{{byte_array::base64, unsigned_field_element::UfeHex}},"
+        );
+        writeln!(out, "    types::FieldElement,");
+        writeln!(out, "}};");
+    } else {
+        writeln!(out);
+        writeln!(out, "use crate::{{");
+        writeln!(
+            out,
+            "    serde::{{byte_array::base64, unsigned_field_element::UfeHex}},"
+        );
+        writeln!(out, "    types::FieldElement,");
+        writeln!(out, "}};");
+    }
+
+    writeln!(out);
+
+    // In later versions this type is still defined by never actually used
+    if profile.version == SpecVersion::V0_1_0 && module != OutputModule::Shared {
+        writeln!(
+            out,
+            "pub use starknet_core::types::L1Address as EthAddress;"
+        );
+        writeln!(out);
+    }
+
+    match module {
+        OutputModule::Single => writeln!(out, "use super::{{serde_impls::NumAsHex, *}};"),
+        OutputModule::Shared | OutputModule::Versioned => {
+            writeln!(out, "use super::super::{{serde_impls::NumAsHex, *}};")
+        }
+    }
+    writeln!(out);
+
+    if module == OutputModule::Versioned {
+        let mut exports = vec![];
+        if cli.builders {
+            exports.push(String::from("BuilderError"));
+        }
+        if cli.client {
+            exports.push(String::from("JsonRpcTransport"));
+        }
+        if !profile.borrowed_types.is_empty() {
+            exports.push(String::from("LazyFieldElement"));
+        }
+        for rust_type in result
+            .all_types()
+            .filter(|rust_type| shared_types.contains(&rust_type.name))
+        {
+            exports.push(rust_type.name.clone());
+            if let RustTypeKind::Struct(content) = &rust_type.content {
+                if content.extra_ref_type {
+                    exports.push(format!("{}Ref", rust_type.name));
+                }
+                if content.extra_borrowed_type {
+                    exports.push(format!("{}Borrowed", rust_type.name));
+                }
+                if cli.builders {
+                    exports.push(format!("{}Builder", rust_type.name));
+                }
+            }
+            if let RustTypeKind::OneOf(content) = &rust_type.content {
+                if content.extra_ref_type {
+                    exports.push(format!("{}Ref", rust_type.name));
+                }
+                if content.extra_borrowed_type {
+                    exports.push(format!("{}Borrowed", rust_type.name));
+                }
+            }
+        }
+
+        if !exports.is_empty() {
+            writeln!(out, "pub use super::shared::{{");
+            for export in exports.iter() {
+                writeln!(out, "    {export},");
+            }
+            writeln!(out, "}};");
+            writeln!(out);
+        }
+    }
+
+    if cli.version_negotiation && module != OutputModule::Shared {
+        writeln!(
+            out,
+            "/// Version of the specification this module is generated from."
+        );
+        writeln!(
+            out,
+            "pub const SPEC_VERSION: &str = \"{}\";",
+            profile.version.as_str()
+        );
+        writeln!(out);
+        writeln!(
+            out,
+            "/// Version of the OpenRPC document of the specification, bumped independently of [SPEC_VERSION]."
+        );
+        writeln!(
+            out,
+            "pub const SPEC_DOCUMENT_VERSION: &str = \"{}\";",
+            specs.info.version
+        );
+        writeln!(out);
+    }
+
+    let mut manual_serde_types = vec![];
+
+    for rust_type in owned_types.iter() {
+        if rust_type.need_serde_impls() {
+            manual_serde_types.push(rust_type);
+        }
+
+        rust_type.render_stdout(out);
+
+        writeln!(out);
+    }
+
+    if owns_lazy_field_element {
+        print_lazy_field_element(out);
+        writeln!(out);
+    }
+
+    for adapter in SERDE_WITH_ADAPTERS.iter() {
+        if owned_types
+            .iter()
+            .any(|rust_type| rust_type.uses_adapter(adapter))
+        {
+            print_serde_with_adapter(out, adapter);
+            writeln!(out);
+        }
+    }
+
+    for (ind, rust_type) in manual_serde_types.iter().enumerate() {
+        rust_type.render_serde_stdout(out);
+
+        if ind != manual_serde_types.len() - 1 {
+            writeln!(out);
+        }
+    }
+
+    if cli.oneof_enums {
+        for conversion in resolve_one_of_conversions(&result.model_types)
+            .into_iter()
+            .filter(|conversion| {
+                owned_types
+                    .iter()
+                    .any(|rust_type| rust_type.name == conversion.enum_name)
+            })
+        {
+            writeln!(out);
+            conversion.render_stdout(out);
+        }
+    }
+
+    if module != OutputModule::Shared {
+        if cli.accessor_traits {
+            for accessor_trait in
+                resolve_accessor_traits(&result.model_types, &result.request_response_types)
+            {
+                writeln!(out);
+                accessor_trait.render_stdout(out);
+            }
+        }
+
+        if cli.jsonrpc_envelopes {
+            let envelopes =
+                JsonRpcEnvelopes::resolve(specs).expect("Failed to resolve JSON-RPC envelopes");
+            writeln!(out);
+            envelopes.render_stdout(out);
+
+            if cli.client {
+                let client =
+                    JsonRpcClient::resolve(specs, &envelopes, &result.request_response_types)
+                        .expect("Failed to resolve JSON-RPC client");
+                writeln!(out);
+                client.render_stdout(out, module == OutputModule::Single);
+
+                if cli.mock_provider {
+                    writeln!(out);
+                    client.render_mock_provider_stdout(out);
+                }
+
+                if cli.record_replay {
+                    writeln!(out);
+                    print_record_replay_transports(out);
+                }
+            }
+        }
+    } else if cli.client {
+        writeln!(out);
+        print_json_rpc_transport(out);
+    }
+
+    if cli.builders {
+        if owns_builder_error {
+            writeln!(out);
+            print_builder_error(out);
+        }
+
+        for rust_type in owned_types.iter() {
+            if let RustTypeKind::Struct(content) = &rust_type.content {
+                writeln!(out);
+                content.render_builder_stdout(out, &rust_type.name);
+            }
+        }
+    }
+
+    if cli.arbitrary {
+        writeln!(out);
+        print_arbitrary_helpers(out);
+
+        for rust_type in owned_types.iter() {
+            writeln!(out);
+            rust_type.render_arbitrary_stdout(out);
+        }
+    }
+}
+
+fn resolve_types(
+    specs: &Specification,
+    flatten_option: &FlattenOption,
+    ignore_types: &[String],
+    fixed_fields: &FixedFieldsOptions,
     arc_wrapping: &ArcWrappingOptions,
+    derive_options: &DeriveOptions,
 ) -> Result<TypeResolutionResult> {
     let mut types = vec![];
     let mut req_types: Vec<RustType> = vec![];
     let mut not_implemented_types = vec![];
+    let mut fixed_field_report = vec![];
 
     let flatten_only_types = get_flatten_only_schemas(specs, flatten_option);
 
@@ -1494,15 +3954,33 @@ fn resolve_types(
             Some(content) => content,
             None => {
                 not_implemented_types.push(name.to_owned());
-
-                eprintln!("OneOf enum generation not implemented. Enum not generated for {name}");
                 continue;
             }
         };
 
         if let RustTypeKind::Struct(inner) = &mut content {
             for field in inner.fields.iter_mut() {
-                field.fixed = fixed_fields.find_fixed_field(&rusty_name, &field.name);
+                match (
+                    field.fixed.as_deref(),
+                    fixed_fields.find_fixed_field(&rusty_name, &field.name),
+                ) {
+                    (Some(inferred), Some(configured)) => {
+                        if inferred == configured {
+                            fixed_field_report.push(format!(
+                                "Fixed field `{}.{}` in profile can be removed: inferred from spec",
+                                rusty_name, field.name
+                            ));
+                        }
+                        field.fixed = Some(String::from(configured));
+                    }
+                    (Some(inferred), None) => {
+                        fixed_field_report.push(format!(
+                            "Inferred fixed field `{}.{}`: {}",
+                            rusty_name, field.name, inferred
+                        ));
+                    }
+                    (None, configured) => field.fixed = configured.map(String::from),
+                }
                 field.arc_wrap = arc_wrapping.in_field_wrapped(&rusty_name, &field.name);
             }
         }
@@ -1511,6 +3989,9 @@ fn resolve_types(
             title: title.map(|value| to_starknet_rs_doc(value, true)),
             description: description.map(|value| to_starknet_rs_doc(value, true)),
             name: rusty_name,
+            extra_derives: vec![],
+            removed_derives: vec![],
+            extra_attributes: vec![],
             content,
         });
     }
@@ -1519,8 +4000,12 @@ fn resolve_types(
         title: Some(String::from("JSON-RPC error codes")),
         description: None,
         name: String::from("StarknetError"),
+        extra_derives: vec![],
+        removed_derives: vec![],
+        extra_attributes: vec![],
         content: RustTypeKind::Enum(RustEnum {
             thiserror: true,
+            default_variant: None,
             variants: specs
                 .components
                 .errors
@@ -1555,26 +4040,31 @@ fn resolve_types(
                 serde_rename: None,
                 serde_faltten: false,
                 serializer: field_type.serializer,
+                nested_ref: false,
+                borrowed_type_name: None,
+                nullable: false,
+                serialize_none: false,
+                serde_default: false,
+                flattened_from: vec![],
             });
         }
 
         let request_type = RustType {
             title: Some(format!("Request for method {}", method.name)),
             description: None,
-            name: format!(
-                "{}Request",
-                to_starknet_rs_name(&camel_to_snake_case(
-                    method.name.trim_start_matches("starknet_")
-                ))
-            ),
+            name: format!("{}Request", to_method_type_prefix(&method.name)),
+            extra_derives: vec![],
+            removed_derives: vec![],
+            extra_attributes: vec![],
             content: if request_fields.is_empty() {
                 RustTypeKind::Unit(RustUnit {
-                    serde_as_array: true,
+                    params: Some(method.param_structure),
                 })
             } else {
                 RustTypeKind::Struct(RustStruct {
-                    serde_as_array: true,
+                    params: Some(method.param_structure),
                     extra_ref_type: true,
+                    extra_borrowed_type: false,
                     fields: request_fields,
                 })
             },
@@ -1583,6 +4073,12 @@ fn resolve_types(
         req_types.push(request_type);
     }
 
+    for rust_type in types.iter_mut().chain(req_types.iter_mut()) {
+        rust_type.extra_derives = derive_options.derives_for(&rust_type.name);
+        rust_type.removed_derives = derive_options.removed_derives_for(&rust_type.name);
+        rust_type.extra_attributes = derive_options.attributes_for(&rust_type.name);
+    }
+
     // Sorting the types makes it easier to check diffs in generated code.
     types.sort_by_key(|item| item.name.to_owned());
     req_types.sort_by_key(|item| item.name.to_owned());
@@ -1592,6 +4088,7 @@ fn resolve_types(
         model_types: types,
         request_response_types: req_types,
         not_implemented: not_implemented_types,
+        fixed_field_report,
     })
 }
 
@@ -1610,8 +4107,9 @@ fn schema_to_rust_type_kind(
                 .ok_or_else(|| anyhow::anyhow!(""))?;
             get_schema_fields(redirected_schema, specs, &mut fields, flatten_option)?;
             Some(RustTypeKind::Struct(RustStruct {
-                serde_as_array: false,
+                params: None,
                 extra_ref_type: false,
+                extra_borrowed_type: false,
                 fields,
             }))
         }
@@ -1620,14 +4118,16 @@ fn schema_to_rust_type_kind(
             let mut fields = vec![];
             get_schema_fields(entity, specs, &mut fields, flatten_option)?;
             Some(RustTypeKind::Struct(RustStruct {
-                serde_as_array: false,
+                params: None,
                 extra_ref_type: false,
+                extra_borrowed_type: false,
                 fields,
             }))
         }
         Schema::Primitive(Primitive::String(value)) => match &value.r#enum {
             Some(variants) => Some(RustTypeKind::Enum(RustEnum {
                 thiserror: false,
+                default_variant: None,
                 variants: variants
                     .iter()
                     .map(|item| RustVariant {
@@ -1648,35 +4148,1293 @@ fn schema_to_rust_type_kind(
     })
 }
 
-/// Finds the list of schemas that are used and only used for flattening inside objects
-fn get_flatten_only_schemas(specs: &Specification, flatten_option: &FlattenOption) -> Vec<String> {
-    // We need this for now since we don't search method calls, so we could get false positives
-    const HARD_CODED_NON_FLATTEN_SCHEMAS: [&str; 2] = ["FUNCTION_CALL", "PENDING_STATE_UPDATE"];
+/// Groups the fields of generated structs by the flattened schemas they were copied from. Fields
+/// that are fixed in any of the structs can't be borrowed and are left out of the traits.
+fn resolve_accessor_traits(
+    model_types: &[RustType],
+    request_response_types: &[RustType],
+) -> Vec<AccessorTrait> {
+    let mut traits = IndexMap::<String, AccessorTrait>::new();
+    let mut excluded_fields = HashSet::<(String, String)>::new();
+
+    for rust_type in model_types.iter().chain(request_response_types.iter()) {
+        let content = match &rust_type.content {
+            RustTypeKind::Struct(content) => content,
+            _ => continue,
+        };
+
+        for field in content.fields.iter() {
+            for schema_name in field.flattened_from.iter() {
+                let accessor_trait =
+                    traits
+                        .entry(schema_name.to_owned())
+                        .or_insert_with(|| AccessorTrait {
+                            name: format!("Has{}", to_starknet_rs_name(schema_name)),
+                            schema_name: schema_name.to_owned(),
+                            fields: vec![],
+                            implementors: vec![],
+                            delegating_implementors: vec![],
+                        });
+
+                if !accessor_trait.implementors.contains(&rust_type.name) {
+                    accessor_trait.implementors.push(rust_type.name.clone());
+                }
+
+                match accessor_trait
+                    .fields
+                    .iter()
+                    .find(|item| item.name == field.name)
+                {
+                    Some(existing) => {
+                        if existing.type_name != field.type_name || field.fixed.is_some() {
+                            excluded_fields.insert((schema_name.to_owned(), field.name.clone()));
+                        }
+                    }
+                    None => {
+                        if field.fixed.is_some() {
+                            excluded_fields.insert((schema_name.to_owned(), field.name.clone()));
+                        }
+                        accessor_trait.fields.push(field.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    traits
+        .into_values()
+        .map(|mut accessor_trait| {
+            accessor_trait.fields.retain(|field| {
+                !excluded_fields.contains(&(accessor_trait.schema_name.clone(), field.name.clone()))
+            });
+
+            // Enums can only delegate once all of their variants implement the trait, which might
+            // in turn depend on other enums.
+            loop {
+                let mut changed = false;
+                for rust_type in model_types.iter() {
+                    let content = match &rust_type.content {
+                        RustTypeKind::OneOf(content) => content,
+                        _ => continue,
+                    };
+                    if accessor_trait
+                        .delegating_implementors
+                        .iter()
+                        .any(|(name, _)| name == &rust_type.name)
+                    {
+                        continue;
+                    }
+
+                    if content.variants.iter().all(|variant| {
+                        accessor_trait.implementors.contains(&variant.type_name)
+                            || accessor_trait
+                                .delegating_implementors
+                                .iter()
+                                .any(|(name, _)| name == &variant.type_name)
+                    }) {
+                        accessor_trait.delegating_implementors.push((
+                            rust_type.name.clone(),
+                            content
+                                .variants
+                                .iter()
+                                .map(|variant| variant.name.clone())
+                                .collect(),
+                        ));
+                        changed = true;
+                    }
+                }
+                if !changed {
+                    break;
+                }
+            }
+
+            accessor_trait
+        })
+        .filter(|accessor_trait| !accessor_trait.fields.is_empty())
+        .collect()
+}
+
+/// Removes all methods but `methods` from `specs`, along with the schemas and errors they don't
+/// use directly or transitively.
+fn filter_methods(specs: &mut Specification, methods: &[String]) -> Result<()> {
+    let graph = SchemaGraph::build(specs, |_| true);
+    for method in methods.iter() {
+        if !specs.methods.iter().any(|item| &item.name == method) {
+            anyhow::bail!("Method `{}` not found in spec", method);
+        }
+    }
+
+    let used = graph
+        .dependencies_of(&methods.iter().map(|item| item.as_str()).collect::<Vec<_>>())?
+        .nodes
+        .into_iter()
+        .map(|node| node.name)
+        .collect::<HashSet<_>>();
+
+    specs.methods.retain(|method| used.contains(&method.name));
+    specs
+        .components
+        .schemas
+        .retain(|name, _| used.contains(name));
+    specs
+        .components
+        .errors
+        .retain(|name, _| used.contains(name));
+
+    Ok(())
+}
+
+/// Generates enums for the `oneOf` schemas whose variants all reference other schemas, removing
+/// them from the types not implemented.
+fn resolve_one_of_enums(
+    specs: &Specification,
+    result: &mut TypeResolutionResult,
+    derive_options: &DeriveOptions,
+) -> Result<()> {
+    let mut not_implemented = vec![];
+    let mut generated = vec![];
+
+    for name in result.not_implemented.iter() {
+        let entity = &specs.components.schemas[name];
+        let references = match entity {
+            Schema::OneOf(one_of) if one_of.nullable_variant().is_none() => one_of
+                .one_of
+                .iter()
+                .map(|variant| match variant {
+                    Schema::Ref(reference) => Some(reference),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>(),
+            _ => None,
+        };
+        let references = match references {
+            Some(references) => references,
+            None => {
+                eprintln!("Enum not generated for {name}: not all variants are references");
+                not_implemented.push(name.to_owned());
+                continue;
+            }
+        };
+
+        let mut variants = match references
+            .into_iter()
+            .map(|reference| {
+                let variant_type = get_rust_type_for_field(&Schema::Ref(reference.clone()), specs)?;
+                if variant_type.serializer.is_some() {
+                    anyhow::bail!("variant `{}` needs a custom serializer", reference.name());
+                }
+
+                Ok(RustOneOfVariant {
+                    description: reference
+                        .description
+                        .as_ref()
+                        .map(|value| to_starknet_rs_doc(value, true)),
+                    name: to_one_of_variant_name(name, reference.name()),
+                    type_name: variant_type.type_name,
+                    literal: None,
+                    legal_value: None,
+                    ref_type_name: None,
+                    borrowed_type_name: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(variants) => variants,
+            Err(err) => {
+                eprintln!("Enum not generated for {name}: {err}");
+                not_implemented.push(name.to_owned());
+                continue;
+            }
+        };
+
+        // Falls back to type names when stripping the enum name isn't enough to tell variants
+        // apart.
+        if variants.iter().enumerate().any(|(ind, variant)| {
+            variant.name.is_empty()
+                || variants[..ind]
+                    .iter()
+                    .any(|previous| previous.name == variant.name)
+        }) {
+            for variant in variants.iter_mut() {
+                variant.name = variant.type_name.clone();
+            }
+        }
+
+        let rusty_name = to_starknet_rs_name(name);
+        let description = match entity.description() {
+            Some(description) => Some(description),
+            None => entity.summary(),
+        };
+        generated.push((name.to_owned(), rusty_name.clone()));
+        result.model_types.push(RustType {
+            title: entity.title().map(|value| to_starknet_rs_doc(value, true)),
+            description: description.map(|value| to_starknet_rs_doc(value, true)),
+            extra_derives: derive_options.derives_for(&rusty_name),
+            removed_derives: derive_options.removed_derives_for(&rusty_name),
+            extra_attributes: derive_options.attributes_for(&rusty_name),
+            name: rusty_name,
+            content: RustTypeKind::OneOf(RustOneOf {
+                extra_ref_type: false,
+                extra_borrowed_type: false,
+                variants,
+            }),
+        });
+    }
+
+    // Untagged enums deserialize into the first variant accepting a value, so enums are only
+    // generated if no variant accepts the values of a later one
+    let mut ambiguous = vec![];
+    for (name, rusty_name) in generated.into_iter() {
+        let variants = match result
+            .model_types
+            .iter()
+            .find(|rust_type| rust_type.name == rusty_name)
+            .map(|rust_type| &rust_type.content)
+        {
+            Some(RustTypeKind::OneOf(content)) => &content.variants,
+            _ => continue,
+        };
+
+        if let Some((earlier, later)) = find_overlapping_variants(&result.model_types, variants) {
+            eprintln!(
+                "Enum not generated for {name}: `{}` values also deserialize into `{}`",
+                later.type_name, earlier.type_name
+            );
+            ambiguous.push((name, rusty_name));
+        }
+    }
+    result.model_types.retain(|rust_type| {
+        !ambiguous
+            .iter()
+            .any(|(_, rusty_name)| rusty_name == &rust_type.name)
+    });
+    not_implemented.extend(ambiguous.into_iter().map(|(name, _)| name));
+    not_implemented.sort();
+
+    result.model_types.sort_by_key(|item| item.name.to_owned());
+    result.not_implemented = not_implemented;
+
+    Ok(())
+}
+
+/// Serialized fields of a struct, telling whether values of other structs deserialize into it.
+struct VariantShape {
+    /// Values of fixed fields, which are always serialized and checked when deserializing
+    fixed: Vec<(String, String)>,
+    /// Fields a value needs to have to deserialize into the struct
+    required: Vec<String>,
+    fields: Vec<String>,
+}
+
+impl VariantShape {
+    /// Whether values of the struct with shape `other` deserialize into this one, assuming fields
+    /// of any type match.
+    fn accepts(&self, other: &Self) -> bool {
+        let conflicting = self.fixed.iter().any(|(name, value)| {
+            other
+                .fixed
+                .iter()
+                .any(|(other_name, other_value)| name == other_name && value != other_value)
+        });
+
+        !conflicting && self.required.iter().all(|name| other.fields.contains(name))
+    }
+}
+
+/// Finds a pair of variants where values of the later one also deserialize into the earlier one.
+/// Variants whose shapes can't be known, like types implemented manually, are assumed not to
+/// overlap.
+fn find_overlapping_variants<'a>(
+    types: &[RustType],
+    variants: &'a [RustOneOfVariant],
+) -> Option<(&'a RustOneOfVariant, &'a RustOneOfVariant)> {
+    let shapes = variants
+        .iter()
+        .map(|variant| get_variant_shapes(types, &variant.type_name))
+        .collect::<Vec<_>>();
+
+    for (later_ind, later_shapes) in shapes.iter().enumerate() {
+        for (earlier_ind, earlier_shapes) in shapes[..later_ind].iter().enumerate() {
+            if let (Some(earlier_shapes), Some(later_shapes)) = (earlier_shapes, later_shapes) {
+                if earlier_shapes
+                    .iter()
+                    .any(|earlier| later_shapes.iter().any(|later| earlier.accepts(later)))
+                {
+                    return Some((&variants[earlier_ind], &variants[later_ind]));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Gets the shapes of the structs a value of `type_name` can be, going through nested enums, or
+/// `None` if the type isn't generated as a struct or an enum of structs.
+fn get_variant_shapes(types: &[RustType], type_name: &str) -> Option<Vec<VariantShape>> {
+    match &types.iter().find(|item| item.name == type_name)?.content {
+        RustTypeKind::Struct(content) => {
+            let mut shape = VariantShape {
+                fixed: vec![],
+                required: vec![],
+                fields: vec![],
+            };
+
+            for field in content.fields.iter() {
+                let required =
+                    !field.optional && strip_generic(&field.type_name, "Option").is_none();
+
+                if field.serde_faltten {
+                    let mut inner = get_variant_shapes(types, &field.type_name)?;
+                    if inner.len() != 1 {
+                        return None;
+                    }
+                    let inner = inner.remove(0);
+
+                    shape.fixed.extend(inner.fixed);
+                    if required {
+                        shape.required.extend(inner.required);
+                    }
+                    shape.fields.extend(inner.fields);
+                    continue;
+                }
+
+                let name = field
+                    .serde_rename
+                    .clone()
+                    .unwrap_or_else(|| field.name.clone());
+                match &field.fixed {
+                    Some(value) => shape.fixed.push((name.clone(), value.clone())),
+                    None if required => shape.required.push(name.clone()),
+                    None => {}
+                }
+                shape.fields.push(name);
+            }
+
+            Some(vec![shape])
+        }
+        RustTypeKind::OneOf(content) => {
+            let mut shapes = vec![];
+            for variant in content.variants.iter() {
+                shapes.extend(get_variant_shapes(types, &variant.type_name)?);
+            }
+            Some(shapes)
+        }
+        _ => None,
+    }
+}
+
+/// Generates an `XxxResponse` type for each method whose result is an inline schema rather than a
+/// reference to a named one.
+fn resolve_response_types(
+    specs: &Specification,
+    result: &mut TypeResolutionResult,
+    flatten_option: &FlattenOption,
+    derive_options: &DeriveOptions,
+) -> Result<()> {
+    for method in specs.methods.iter() {
+        let schema = &method.result.schema;
+        let content = match schema {
+            Schema::Ref(_) => continue,
+            Schema::OneOf(one_of) if one_of.nullable_variant().is_none() => {
+                RustTypeKind::OneOf(RustOneOf {
+                    extra_ref_type: false,
+                    extra_borrowed_type: false,
+                    variants: one_of
+                        .one_of
+                        .iter()
+                        .map(|variant| {
+                            let variant_type = get_rust_type_for_field(variant, specs)?;
+                            if variant_type.serializer.is_some() {
+                                anyhow::bail!(
+                                    "Response variant of `{}` needs a custom serializer",
+                                    method.name
+                                );
+                            }
+
+                            // Inline variants are named after their titles. A boolean variant
+                            // titled after a value only accepts that value, e.g. the `False`
+                            // result of `starknet_syncing`.
+                            let literal = match variant {
+                                Schema::Primitive(Primitive::Boolean(boolean)) => boolean
+                                    .title
+                                    .as_ref()
+                                    .and_then(|title| title.to_lowercase().parse::<bool>().ok()),
+                                _ => None,
+                            };
+                            // Older specs leave it untitled and only state it in the description
+                            let legal_value = match variant {
+                                Schema::Primitive(Primitive::Boolean(boolean)) => boolean
+                                    .description
+                                    .as_ref()
+                                    .and_then(|description| {
+                                        description
+                                            .to_lowercase()
+                                            .strip_prefix("only legal value is ")?
+                                            .split(' ')
+                                            .next()?
+                                            .parse::<bool>()
+                                            .ok()
+                                    })
+                                    .filter(|_| literal.is_none()),
+                                _ => None,
+                            };
+
+                            Ok(RustOneOfVariant {
+                                description: variant
+                                    .description()
+                                    .map(|value| to_starknet_rs_doc(value, true)),
+                                name: match variant
+                                    .title()
+                                    .filter(|_| !matches!(variant, Schema::Ref(_)))
+                                {
+                                    Some(title) => {
+                                        to_pascal_case(&title.to_uppercase().replace(' ', "_"))
+                                    }
+                                    None => {
+                                        variant_type
+                                            .type_name
+                                            .get(..1)
+                                            .map(|first| first.to_ascii_uppercase())
+                                            .unwrap_or_default()
+                                            + variant_type.type_name.get(1..).unwrap_or_default()
+                                    }
+                                },
+                                type_name: variant_type.type_name,
+                                literal,
+                                legal_value,
+                                ref_type_name: None,
+                                borrowed_type_name: None,
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                })
+            }
+            Schema::AllOf(_) | Schema::Primitive(Primitive::Object(_)) => {
+                schema_to_rust_type_kind(specs, schema, flatten_option)?.ok_or_else(|| {
+                    anyhow::anyhow!("Unable to resolve response type of `{}`", method.name)
+                })?
+            }
+            _ => {
+                let field_type = get_rust_type_for_field(schema, specs)?;
+                RustTypeKind::Wrapper(RustWrapper {
+                    type_name: field_type.type_name,
+                    serializer: field_type.serializer,
+                })
+            }
+        };
+
+        let name = format!("{}Response", to_method_type_prefix(&method.name));
+        result.request_response_types.push(RustType {
+            title: Some(format!("Response for method {}", method.name)),
+            description: method
+                .result
+                .description
+                .as_ref()
+                .map(|value| to_starknet_rs_doc(value, true)),
+            extra_derives: derive_options.derives_for(&name),
+            removed_derives: derive_options.removed_derives_for(&name),
+            extra_attributes: derive_options.attributes_for(&name),
+            name,
+            content,
+        });
+    }
+
+    result
+        .request_response_types
+        .sort_by_key(|item| item.name.to_owned());
+
+    Ok(())
+}
+
+/// Finds the conversions between generated `oneOf` enums and the types nested inside them,
+/// directly or through other enums. Types reachable through more than one path are skipped as
+/// the conversion would be ambiguous.
+fn resolve_one_of_conversions(model_types: &[RustType]) -> Vec<OneOfConversion> {
+    let enums = model_types
+        .iter()
+        .filter_map(|rust_type| match &rust_type.content {
+            RustTypeKind::OneOf(content) => Some((rust_type.name.as_str(), content)),
+            _ => None,
+        })
+        .collect::<IndexMap<_, _>>();
+
+    let mut conversions = vec![];
+    for enum_name in enums.keys() {
+        let mut enum_conversions = vec![];
+        collect_one_of_conversions(&enums, enum_name, enum_name, vec![], &mut enum_conversions);
+
+        for conversion in enum_conversions.iter() {
+            if enum_conversions
+                .iter()
+                .filter(|item| item.type_name == conversion.type_name)
+                .count()
+                > 1
+            {
+                eprintln!(
+                    "Conversion between `{}` and `{}` not generated: type nested more than once",
+                    conversion.enum_name, conversion.type_name
+                );
+            } else if conversion.type_name != conversion.enum_name {
+                conversions.push(conversion.clone());
+            }
+        }
+    }
+
+    conversions
+}
+
+fn collect_one_of_conversions(
+    enums: &IndexMap<&str, &RustOneOf>,
+    root_name: &str,
+    enum_name: &str,
+    path: Vec<(String, String)>,
+    conversions: &mut Vec<OneOfConversion>,
+) {
+    let content = enums[enum_name];
+
+    for variant in content.variants.iter() {
+        let mut variant_path = path.clone();
+        variant_path.push((enum_name.to_owned(), variant.name.clone()));
+
+        conversions.push(OneOfConversion {
+            enum_name: root_name.to_owned(),
+            type_name: variant.type_name.clone(),
+            exhaustive: variant_path
+                .iter()
+                .all(|(name, _)| enums[name.as_str()].variants.len() == 1),
+            path: variant_path.clone(),
+        });
+
+        if enums.contains_key(variant.type_name.as_str())
+            && !variant_path
+                .iter()
+                .any(|(name, _)| name == &variant.type_name)
+        {
+            collect_one_of_conversions(
+                enums,
+                root_name,
+                &variant.type_name,
+                variant_path,
+                conversions,
+            );
+        }
+    }
+}
+
+/// Applies the optional field policies to all generated structs.
+fn resolve_optional_fields(
+    result: &mut TypeResolutionResult,
+    options: &OptionalFieldOptions,
+) -> Result<()> {
+    for item in options.fields.iter() {
+        let field = result
+            .model_types
+            .iter()
+            .chain(result.request_response_types.iter())
+            .filter(|rust_type| rust_type.name == item.type_name)
+            .find_map(|rust_type| match &rust_type.content {
+                RustTypeKind::Struct(content) => content
+                    .fields
+                    .iter()
+                    .find(|field| field.name == item.field_name),
+                _ => None,
+            });
+
+        match field {
+            Some(field) if field.optional => {}
+            Some(_) => anyhow::bail!(
+                "Cannot set optional field policy for `{}.{}`: field is not optional",
+                item.type_name,
+                item.field_name
+            ),
+            None => anyhow::bail!(
+                "Cannot set optional field policy for `{}.{}`: field not found",
+                item.type_name,
+                item.field_name
+            ),
+        }
+    }
+
+    let derives = result
+        .model_types
+        .iter()
+        .chain(result.request_response_types.iter())
+        .map(|rust_type| (rust_type.name.clone(), rust_type.derives()))
+        .collect::<Vec<_>>();
+    let derives = derives
+        .iter()
+        .map(|(name, derives)| (name.as_str(), derives.clone()))
+        .collect::<Vec<_>>();
+
+    for rust_type in result
+        .model_types
+        .iter_mut()
+        .chain(result.request_response_types.iter_mut())
+    {
+        if let RustTypeKind::Struct(content) = &mut rust_type.content {
+            for field in content.fields.iter_mut().filter(|field| field.optional) {
+                // Global policies only cover `Option` fields. Optional method params of other
+                // types are only changed when configured explicitly.
+                let configured = options
+                    .fields
+                    .iter()
+                    .any(|item| item.type_name == rust_type.name && item.field_name == field.name);
+                if !field.is_option() && !configured {
+                    continue;
+                }
+
+                field.serialize_none = options.none_serialization_for(&rust_type.name, &field.name)
+                    == NoneSerialization::Null;
+                field.serde_default = options.serde_default_for(&rust_type.name, &field.name);
+
+                // Like derives, types implemented manually are assumed to implement `Default`
+                if field.serde_default
+                    && type_implements(&field.type_name, "Default", &derives) == Ok(false)
+                {
+                    anyhow::bail!(
+                        "Cannot add `#[serde(default)]` to `{}.{}`: `{}` does not implement \
+                        `Default`",
+                        rust_type.name,
+                        field.name,
+                        field.type_name
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Marks the structs and `oneOf` enums getting `Ref` variants. Struct fields only use the `Ref`
+/// variants of their types if those are requested too, while enums, having no data of their own,
+/// always hold the `Ref` variants of the structs and enums they contain.
+fn resolve_ref_types(types: &mut [RustType], ref_types: &[String]) -> Result<()> {
+    let mut pending = ref_types.to_vec();
+    let mut resolved = HashSet::<String>::new();
+
+    while let Some(type_name) = pending.pop() {
+        if resolved.contains(&type_name) {
+            continue;
+        }
+
+        match types.iter().find(|item| item.name == type_name) {
+            Some(RustType {
+                content: RustTypeKind::Struct(_),
+                ..
+            }) => {}
+            Some(RustType {
+                content: RustTypeKind::OneOf(content),
+                ..
+            }) => {
+                for variant in content.variants.iter() {
+                    if types.iter().any(|item| {
+                        item.name == variant.type_name
+                            && matches!(
+                                item.content,
+                                RustTypeKind::Struct(_) | RustTypeKind::OneOf(_)
+                            )
+                    }) {
+                        pending.push(variant.type_name.clone());
+                    }
+                }
+            }
+            Some(_) => anyhow::bail!(
+                "Cannot generate `Ref` variant for `{}`: not a generated struct or oneOf enum",
+                type_name
+            ),
+            None => anyhow::bail!(
+                "Cannot generate `Ref` variant for `{}`: type not generated \
+                (`oneOf` enums need `--oneof-enums`)",
+                type_name
+            ),
+        }
+
+        resolved.insert(type_name);
+    }
+
+    for rust_type in types.iter_mut() {
+        match &mut rust_type.content {
+            RustTypeKind::Struct(content) => {
+                content.extra_ref_type = resolved.contains(&rust_type.name);
+
+                if content.extra_ref_type {
+                    for field in content.fields.iter_mut() {
+                        field.nested_ref = resolved.iter().any(|ref_type| {
+                            field.type_name == *ref_type
+                                || strip_generic(&field.type_name, "Option")
+                                    == Some(ref_type.as_str())
+                        });
+                    }
+                }
+            }
+            RustTypeKind::OneOf(content) => {
+                content.extra_ref_type = resolved.contains(&rust_type.name);
+
+                if content.extra_ref_type {
+                    for variant in content.variants.iter_mut() {
+                        variant.ref_type_name = Some(if resolved.contains(&variant.type_name) {
+                            format!("{}Ref<'a>", variant.type_name)
+                        } else {
+                            format!("&'a {}", variant.type_name)
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Marks the structs and `oneOf` enums getting `Borrowed` variants, which include all of them
+/// reachable from the requested ones, and computes the types of their fields and variants in the
+/// `Borrowed` variants. Other types are kept owned.
+fn resolve_borrowed_types(types: &mut [RustType], borrowed_types: &[String]) -> Result<()> {
+    let mut pending = borrowed_types.to_vec();
+    let mut resolved = HashSet::<String>::new();
+
+    while let Some(type_name) = pending.pop() {
+        if resolved.contains(&type_name) {
+            continue;
+        }
+
+        let contained_types = match types.iter().find(|item| item.name == type_name) {
+            Some(RustType {
+                content: RustTypeKind::Struct(content),
+                ..
+            }) => content
+                .fields
+                .iter()
+                .map(|field| field.type_name.as_str())
+                .collect::<Vec<_>>(),
+            Some(RustType {
+                content: RustTypeKind::OneOf(content),
+                ..
+            }) => content
+                .variants
+                .iter()
+                .map(|variant| variant.type_name.as_str())
+                .collect(),
+            Some(_) => anyhow::bail!(
+                "Cannot generate `Borrowed` variant for `{}`: not a generated struct or oneOf enum",
+                type_name
+            ),
+            None => anyhow::bail!(
+                "Cannot generate `Borrowed` variant for `{}`: type not generated \
+                (`oneOf` enums need `--oneof-enums`)",
+                type_name
+            ),
+        };
+
+        for mut contained_type in contained_types.into_iter() {
+            while let Some(inner) = strip_generic(contained_type, "Vec")
+                .or_else(|| strip_generic(contained_type, "Option"))
+            {
+                contained_type = inner;
+            }
+
+            if types.iter().any(|item| {
+                item.name == contained_type
+                    && matches!(
+                        item.content,
+                        RustTypeKind::Struct(_) | RustTypeKind::OneOf(_)
+                    )
+            }) {
+                pending.push(contained_type.to_owned());
+            }
+        }
+
+        resolved.insert(type_name);
+    }
+
+    for rust_type in types.iter_mut() {
+        match &mut rust_type.content {
+            RustTypeKind::Struct(content) => {
+                content.extra_borrowed_type = resolved.contains(&rust_type.name);
+
+                if content.extra_borrowed_type {
+                    for field in content.fields.iter_mut() {
+                        field.borrowed_type_name =
+                            Some(to_borrowed_type_name(&field.type_name, &resolved));
+                    }
+                }
+            }
+            RustTypeKind::OneOf(content) => {
+                content.extra_borrowed_type = resolved.contains(&rust_type.name);
+
+                if content.extra_borrowed_type {
+                    for variant in content.variants.iter_mut() {
+                        variant.borrowed_type_name =
+                            Some(to_borrowed_type_name(&variant.type_name, &resolved));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn to_borrowed_type_name(type_name: &str, borrowed_types: &HashSet<String>) -> String {
+    if let Some(inner) = strip_generic(type_name, "Vec") {
+        format!("Vec<{}>", to_borrowed_type_name(inner, borrowed_types))
+    } else if let Some(inner) = strip_generic(type_name, "Option") {
+        format!("Option<{}>", to_borrowed_type_name(inner, borrowed_types))
+    } else if type_name == "FieldElement" {
+        String::from("LazyFieldElement<'a>")
+    } else if type_name == "String" {
+        String::from("Cow<'a, str>")
+    } else if borrowed_types.contains(type_name) {
+        format!("{type_name}Borrowed<'a>")
+    } else {
+        type_name.to_owned()
+    }
+}
+
+/// Builds the `serde_as` adapter making `Cow` strings in `type_name` borrow from the input. Serde
+/// only borrows a `Cow` by itself when it's the field's type, not when it's inside a `Vec` or
+/// `Option`.
+fn to_borrow_cow_adapter(type_name: &str) -> Option<String> {
+    if let Some(inner) = strip_generic(type_name, "Vec") {
+        to_borrow_cow_adapter(inner).map(|inner| format!("Vec<{inner}>"))
+    } else if let Some(inner) = strip_generic(type_name, "Option") {
+        to_borrow_cow_adapter(inner).map(|inner| format!("Option<{inner}>"))
+    } else if type_name == "Cow<'a, str>" {
+        Some(String::from("serde_with::BorrowCow"))
+    } else {
+        None
+    }
+}
+
+/// Makes sure all traits derived for generated types are also implemented by their fields.
+///
+/// Only common `std` traits are checked, and types not generated here (those implemented manually
+/// in `starknet-rs`) are assumed to implement everything asked for.
+fn check_derives<'a>(types: impl Iterator<Item = &'a RustType> + Clone) -> Result<()> {
+    const CHECKED_TRAITS: [&str; 9] = [
+        "Debug",
+        "Clone",
+        "Copy",
+        "PartialEq",
+        "Eq",
+        "Hash",
+        "PartialOrd",
+        "Ord",
+        "Default",
+    ];
+    /// Default derives that other generated code relies on
+    const REQUIRED_TRAITS: [&str; 5] = [
+        "Debug",
+        "Clone",
+        "Serialize",
+        "Deserialize",
+        "thiserror::Error",
+    ];
+
+    let derives = types
+        .clone()
+        .map(|rust_type| (rust_type.name.as_str(), rust_type.derives()))
+        .collect::<Vec<_>>();
+
+    let mut assumed = IndexMap::<String, Vec<String>>::new();
+
+    for rust_type in types {
+        for removed in rust_type.removed_derives.iter() {
+            if REQUIRED_TRAITS.contains(&removed.as_str())
+                && rust_type
+                    .content
+                    .default_derives()
+                    .contains(&removed.as_str())
+            {
+                anyhow::bail!(
+                    "Cannot remove `{}` from `{}` as generated code relies on it",
+                    removed,
+                    rust_type.name
+                );
+            }
+        }
+
+        let type_derives = derives
+            .iter()
+            .find(|(name, _)| name == &rust_type.name)
+            .map(|(_, derives)| derives)
+            .expect("type derives must exist");
+
+        for derive in type_derives.iter() {
+            for required in match derive.as_str() {
+                "Copy" => &["Clone"][..],
+                "Eq" => &["PartialEq"][..],
+                "PartialOrd" => &["PartialEq"][..],
+                "Ord" => &["PartialOrd", "Eq"][..],
+                _ => &[][..],
+            } {
+                if !type_derives.iter().any(|item| item == required) {
+                    anyhow::bail!(
+                        "Cannot derive `{}` for `{}` without also deriving `{}`",
+                        derive,
+                        rust_type.name,
+                        required
+                    );
+                }
+            }
+
+            // Default derives are always satisfiable
+            if !CHECKED_TRAITS.contains(&derive.as_str())
+                || rust_type
+                    .content
+                    .default_derives()
+                    .contains(&derive.as_str())
+            {
+                continue;
+            }
+
+            match &rust_type.content {
+                RustTypeKind::Enum(content)
+                    if derive == "Default" && content.default_variant.is_none() =>
+                {
+                    anyhow::bail!(
+                        "Cannot derive `Default` for enum `{}` as no variant is marked \
+                        `#[default]`",
+                        rust_type.name
+                    );
+                }
+                RustTypeKind::OneOf(_) if derive == "Default" => {
+                    anyhow::bail!(
+                        "Cannot derive `Default` for enum `{}` as only unit variants can be \
+                        marked `#[default]`",
+                        rust_type.name
+                    );
+                }
+                _ => {}
+            }
+
+            for (field_name, field_type) in derive_field_types(rust_type) {
+                match type_implements(&field_type, derive, &derives) {
+                    Ok(true) => {}
+                    Ok(false) => anyhow::bail!(
+                        "Cannot derive `{}` for `{}` as field `{}` of type `{}` does not \
+                        implement it",
+                        derive,
+                        rust_type.name,
+                        field_name,
+                        field_type
+                    ),
+                    Err(unknown_type) => {
+                        let traits = assumed.entry(unknown_type).or_default();
+                        if !traits.contains(derive) {
+                            traits.push(derive.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for (type_name, traits) in assumed.iter() {
+        eprintln!(
+            "Assuming manually implemented type `{}` implements: {}",
+            type_name,
+            traits
+                .iter()
+                .map(|item| format!("`{item}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Names and types of the fields or variants that must implement the traits derived for a type.
+fn derive_field_types(rust_type: &RustType) -> Vec<(&str, String)> {
+    match &rust_type.content {
+        RustTypeKind::Struct(content) => content
+            .fields
+            .iter()
+            .filter(|field| field.fixed.is_none())
+            .map(|field| {
+                (
+                    field.name.as_str(),
+                    if field.arc_wrap {
+                        format!("Arc<{}>", field.type_name)
+                    } else {
+                        field.type_name.clone()
+                    },
+                )
+            })
+            .collect(),
+        RustTypeKind::Wrapper(content) => vec![("0", content.type_name.clone())],
+        RustTypeKind::Enum(_) | RustTypeKind::Unit(_) => vec![],
+        RustTypeKind::OneOf(content) => content
+            .variants
+            .iter()
+            .map(|variant| (variant.name.as_str(), variant.type_name.clone()))
+            .collect(),
+    }
+}
+
+/// Marks the `#[default]` variants of enums. `Default` derived for all types is then only kept
+/// for the types that can derive it: enums with a `#[default]` variant, and other types whose
+/// fields all implement it. Types with `Default` derived explicitly are checked like any other
+/// derive instead.
+fn resolve_default_derives(
+    result: &mut TypeResolutionResult,
+    derive_options: &DeriveOptions,
+) -> Result<()> {
+    for rust_type in result
+        .model_types
+        .iter_mut()
+        .chain(result.request_response_types.iter_mut())
+    {
+        let Some(variant) = derive_options.default_variant_for(&rust_type.name) else {
+            continue;
+        };
+        match &mut rust_type.content {
+            RustTypeKind::Enum(content) => {
+                if !content.variants.iter().any(|item| item.name == variant) {
+                    anyhow::bail!("Enum `{}` has no variant `{}`", rust_type.name, variant);
+                }
+                content.default_variant = Some(variant.to_owned());
+            }
+            _ => anyhow::bail!(
+                "Cannot mark a `#[default]` variant for `{}`: not an enum of unit variants",
+                rust_type.name
+            ),
+        }
+    }
+
+    if !derive_options.derives.iter().any(|item| item == "Default") {
+        return Ok(());
+    }
+
+    let explicit_types = derive_options
+        .types
+        .iter()
+        .filter(|item| item.derives.iter().any(|derive| derive == "Default"))
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+
+    // Types only derive `Default` if their fields still do, so this runs until no type changes
+    loop {
+        let derives = result
+            .all_types()
+            .map(|rust_type| (rust_type.name.as_str(), rust_type.derives()))
+            .collect::<Vec<_>>();
+
+        let not_derivable = result
+            .all_types()
+            .filter(|rust_type| {
+                !explicit_types.contains(&rust_type.name.as_str())
+                    && rust_type.derives().iter().any(|item| item == "Default")
+                    && match &rust_type.content {
+                        RustTypeKind::Enum(content) => content.default_variant.is_none(),
+                        RustTypeKind::OneOf(_) => true,
+                        _ => derive_field_types(rust_type).iter().any(|(_, field_type)| {
+                            type_implements(field_type, "Default", &derives) == Ok(false)
+                        }),
+                    }
+            })
+            .map(|rust_type| rust_type.name.clone())
+            .collect::<Vec<_>>();
+
+        if not_derivable.is_empty() {
+            return Ok(());
+        }
+
+        for rust_type in result
+            .model_types
+            .iter_mut()
+            .chain(result.request_response_types.iter_mut())
+            .filter(|rust_type| not_derivable.contains(&rust_type.name))
+        {
+            rust_type.removed_derives.push(String::from("Default"));
+        }
+    }
+}
+
+/// Checks whether `type_name` implements the `std` trait `trait_name`. Returns the name of the
+/// type in question as error if it's neither a well-known nor a generated type.
+fn type_implements(
+    type_name: &str,
+    trait_name: &str,
+    generated_derives: &[(&str, Vec<String>)],
+) -> std::result::Result<bool, String> {
+    if let Some(inner) = strip_generic(type_name, "Vec") {
+        return match trait_name {
+            "Copy" => Ok(false),
+            "Default" => Ok(true),
+            _ => type_implements(inner, trait_name, generated_derives),
+        };
+    }
+    if let Some(inner) = strip_generic(type_name, "Option") {
+        return match trait_name {
+            "Default" => Ok(true),
+            _ => type_implements(inner, trait_name, generated_derives),
+        };
+    }
+    if let Some(inner) = strip_generic(type_name, "Arc") {
+        return match trait_name {
+            "Copy" => Ok(false),
+            _ => type_implements(inner, trait_name, generated_derives),
+        };
+    }
+
+    match type_name {
+        "u8" | "u64" | "bool" | "()" | "FieldElement" => Ok(true),
+        "f64" => Ok(!matches!(trait_name, "Eq" | "Hash" | "Ord")),
+        "String" => Ok(trait_name != "Copy"),
+        _ => match generated_derives
+            .iter()
+            .find(|(name, _)| name == &type_name)
+        {
+            Some((_, derives)) => Ok(derives.iter().any(|item| item == trait_name)),
+            None => Err(type_name.to_owned()),
+        },
+    }
+}
+
+/// Finds the list of schemas that are used and only used for flattening inside objects
+fn get_flatten_only_schemas(specs: &Specification, flatten_option: &FlattenOption) -> Vec<String> {
+    let mut flatten_fields = HashSet::<String>::new();
+    let mut non_flatten_fields = HashSet::<String>::new();
+
+    for (_, schema) in specs.components.schemas.iter() {
+        visit_schema_for_flatten_only(
+            schema,
+            flatten_option,
+            &mut flatten_fields,
+            &mut non_flatten_fields,
+        );
+    }
+
+    // Schemas used directly by methods are needed as standalone types
+    for schema in get_method_and_error_schemas(specs) {
+        match schema {
+            Schema::Ref(reference) => {
+                non_flatten_fields.insert(reference.name().to_owned());
+            }
+            _ => visit_schema_for_flatten_only(
+                schema,
+                flatten_option,
+                &mut flatten_fields,
+                &mut non_flatten_fields,
+            ),
+        }
+    }
+
+    flatten_fields
+        .into_iter()
+        .filter(|item| !non_flatten_fields.contains(item))
+        .collect()
+}
+
+/// Gets the schemas of method params, method results and error data.
+fn get_method_and_error_schemas(specs: &Specification) -> impl Iterator<Item = &Schema> {
+    let method_schemas = specs.methods.iter().flat_map(|method| {
+        method
+            .params
+            .iter()
+            .map(|param| &param.schema)
+            .chain(std::iter::once(&method.result.schema))
+    });
+    let error_schemas = specs
+        .components
+        .errors
+        .values()
+        .filter_map(|error| match error {
+            ErrorType::Error(error) => error.data.as_ref(),
+            ErrorType::Reference(_) => None,
+        });
+
+    method_schemas.chain(error_schemas)
+}
+
+/// Finds the names of all schemas referenced as `allOf` fragments in a schema.
+/// Makes sure that arbitrary values can be generated for all types, which they can't be for
+/// strings with a `pattern` unless those map to types other than `String`.
+fn check_arbitrary_patterns(specs: &Specification, ignore_types: &[String]) -> Result<()> {
+    let schemas = specs
+        .components
+        .schemas
+        .iter()
+        .filter(|(name, _)| !ignore_types.contains(name) && get_field_type_override(name).is_none())
+        .map(|(name, schema)| (name.as_str(), schema))
+        .chain(specs.methods.iter().flat_map(|method| {
+            method
+                .params
+                .iter()
+                .map(|param| &param.schema)
+                .chain(std::iter::once(&method.result.schema))
+                .map(|schema| (method.name.as_str(), schema))
+        }));
+
+    for (name, schema) in schemas {
+        if let Some(pattern) = get_string_patterns(schema, specs)?.first() {
+            anyhow::bail!(
+                "Arbitrary strings of `{}` can't be made to match pattern `{}`",
+                name,
+                pattern
+            );
+        }
+    }
 
-    let mut flatten_fields = HashSet::<String>::new();
-    let mut non_flatten_fields = HashSet::<String>::new();
+    Ok(())
+}
 
-    for (_, schema) in specs.components.schemas.iter() {
-        visit_schema_for_flatten_only(
-            schema,
-            flatten_option,
-            &mut flatten_fields,
-            &mut non_flatten_fields,
-        );
+/// Finds the `pattern`s of the string schemas mapped to `String`, without following references.
+fn get_string_patterns(schema: &Schema, specs: &Specification) -> Result<Vec<String>> {
+    let nested = match schema {
+        Schema::Ref(_) => vec![],
+        Schema::OneOf(one_of) => one_of.one_of.iter().collect(),
+        Schema::AllOf(all_of) => all_of.all_of.iter().collect(),
+        Schema::Primitive(Primitive::Object(object)) => object.properties.values().collect(),
+        Schema::Primitive(Primitive::Array(array)) => vec![array.items.as_ref()],
+        Schema::Primitive(Primitive::String(string)) => {
+            return Ok(match &string.pattern {
+                Some(pattern) if get_rust_type_for_field(schema, specs)?.type_name == "String" => {
+                    vec![pattern.to_owned()]
+                }
+                _ => vec![],
+            });
+        }
+        Schema::Primitive(_) => vec![],
+    };
+
+    let mut patterns = vec![];
+    for schema in nested {
+        patterns.extend(get_string_patterns(schema, specs)?);
     }
+    Ok(patterns)
+}
 
-    flatten_fields
-        .into_iter()
-        .filter_map(|item| {
-            if non_flatten_fields.contains(&item)
-                || HARD_CODED_NON_FLATTEN_SCHEMAS.contains(&item.as_str())
-            {
-                None
-            } else {
-                Some(item)
-            }
-        })
-        .collect()
+fn get_all_of_fragments(schema: &Schema) -> Vec<String> {
+    match schema {
+        Schema::Ref(_) => vec![],
+        Schema::OneOf(one_of) => one_of
+            .one_of
+            .iter()
+            .flat_map(get_all_of_fragments)
+            .collect(),
+        Schema::AllOf(all_of) => all_of
+            .all_of
+            .iter()
+            .flat_map(|fragment| match fragment {
+                Schema::Ref(reference) => vec![reference.name().to_owned()],
+                _ => get_all_of_fragments(fragment),
+            })
+            .collect(),
+        Schema::Primitive(Primitive::Object(object)) => object
+            .properties
+            .values()
+            .flat_map(get_all_of_fragments)
+            .collect(),
+        Schema::Primitive(Primitive::Array(array)) => get_all_of_fragments(&array.items),
+        Schema::Primitive(_) => vec![],
+    }
 }
 
 fn visit_schema_for_flatten_only(
@@ -1706,12 +5464,7 @@ fn visit_schema_for_flatten_only(
             for fragment in all_of.all_of.iter() {
                 match fragment {
                     Schema::Ref(reference) => {
-                        let should_flatten = match flatten_option {
-                            FlattenOption::All => true,
-                            FlattenOption::Selected(flatten_types) => {
-                                flatten_types.contains(&reference.name().to_owned())
-                            }
-                        };
+                        let should_flatten = flatten_option.should_flatten(reference.name());
 
                         if should_flatten {
                             flatten_fields.insert(reference.name().to_owned());
@@ -1785,15 +5538,15 @@ fn get_schema_fields(
             for item in value.all_of.iter() {
                 match item {
                     Schema::Ref(reference) => {
-                        let should_flatten = match flatten_option {
-                            FlattenOption::All => true,
-                            FlattenOption::Selected(flatten_types) => {
-                                flatten_types.contains(&reference.name().to_owned())
-                            }
-                        };
+                        let should_flatten = flatten_option.should_flatten(reference.name());
 
                         if should_flatten {
+                            let ind_first_field = fields.len();
                             get_schema_fields(item, specs, fields, flatten_option)?;
+
+                            for field in fields[ind_first_field..].iter_mut() {
+                                field.flattened_from.push(reference.name().to_owned());
+                            }
                         } else {
                             fields.push(RustField {
                                 description: reference.description.to_owned(),
@@ -1805,6 +5558,12 @@ fn get_schema_fields(
                                 serde_rename: None,
                                 serde_faltten: true,
                                 serializer: None,
+                                nested_ref: false,
+                                borrowed_type_name: None,
+                                nullable: false,
+                                serialize_none: false,
+                                serde_default: false,
+                                flattened_from: vec![],
                             });
                         }
                     }
@@ -1826,7 +5585,13 @@ fn get_schema_fields(
                     },
                 };
 
-                let field_type = get_rust_type_for_field(prop_value, specs)?;
+                let (field_type, field_nullable) = match prop_value {
+                    Schema::OneOf(one_of) => match one_of.nullable_variant() {
+                        Some(variant) => (get_rust_type_for_field(variant, specs)?, true),
+                        None => (get_rust_type_for_field(prop_value, specs)?, false),
+                    },
+                    _ => (get_rust_type_for_field(prop_value, specs)?, false),
+                };
 
                 let field_name = to_rust_field_name(name);
                 let rename = if name == &field_name {
@@ -1840,27 +5605,55 @@ fn get_schema_fields(
                     Some(required) => !required.contains(name),
                     None => false,
                 };
-                let type_name = if field_optional {
-                    format!("Option<{}>", field_type.type_name)
-                } else {
-                    field_type.type_name
+                let type_name = match (field_optional, field_nullable) {
+                    (true, true) => format!("Option<Option<{}>>", field_type.type_name),
+                    (true, false) | (false, true) => format!("Option<{}>", field_type.type_name),
+                    (false, false) => field_type.type_name,
                 };
-                let serializer = if field_optional {
-                    field_type.serializer.map(|value| value.to_optional())
-                } else {
-                    field_type.serializer
+                let serializer = match (field_optional, field_nullable) {
+                    (true, true) => {
+                        if field_type.serializer.is_some() {
+                            anyhow::bail!(
+                                "Serializer overrides on fields both optional and nullable are not \
+                                supported: `{}`",
+                                name
+                            );
+                        }
+                        None
+                    }
+                    (true, false) | (false, true) => field_type
+                        .serializer
+                        .map(|value| value.to_optional())
+                        .transpose()?,
+                    (false, false) => field_type.serializer,
+                };
+
+                // Required single-value strings are discriminators implied by the type
+                let fixed = match prop_value {
+                    Schema::Primitive(Primitive::String(value))
+                        if !field_optional && type_name == "String" =>
+                    {
+                        value.single_value().map(|value| format!("{value:?}"))
+                    }
+                    _ => None,
                 };
 
                 fields.push(RustField {
                     description: doc_string.map(|value| to_starknet_rs_doc(value, false)),
                     name: field_name,
                     optional: field_optional,
-                    fixed: None,
+                    fixed,
                     arc_wrap: false,
                     type_name,
                     serde_rename: rename,
                     serde_faltten: false,
                     serializer,
+                    nested_ref: false,
+                    borrowed_type_name: None,
+                    nullable: field_nullable,
+                    serialize_none: false,
+                    serde_default: false,
+                    flattened_from: vec![],
                 });
             }
         }
@@ -1889,24 +5682,31 @@ fn get_rust_type_for_field(schema: &Schema, specs: &Specification) -> Result<Rus
                 }),
             )
         }
-        Schema::OneOf(_) => {
-            anyhow::bail!("Anonymous oneOf types should not be used for properties");
-        }
+        Schema::OneOf(one_of) => match one_of.nullable_variant() {
+            Some(variant) => {
+                let variant_type = get_rust_type_for_field(variant, specs)?;
+                Ok(RustFieldType {
+                    type_name: format!("Option<{}>", variant_type.type_name),
+                    serializer: variant_type
+                        .serializer
+                        .map(|value| value.to_optional())
+                        .transpose()?,
+                })
+            }
+            None => {
+                anyhow::bail!("Anonymous oneOf types should not be used for properties");
+            }
+        },
         Schema::AllOf(_) => {
             anyhow::bail!("Anonymous allOf types should not be used for properties");
         }
         Schema::Primitive(value) => match value {
             Primitive::Array(value) => {
                 let item_type = get_rust_type_for_field(&value.items, specs)?;
-                let serializer = match item_type.serializer {
-                    Some(SerializerOverride::Serde(_)) => {
-                        todo!("Array wrapper for #[serde(with)] not implemented")
-                    }
-                    Some(SerializerOverride::SerdeAs(serializer)) => {
-                        Some(SerializerOverride::SerdeAs(format!("Vec<{serializer}>")))
-                    }
-                    None => None,
-                };
+                let serializer = item_type
+                    .serializer
+                    .map(|value| value.to_array())
+                    .transpose()?;
                 Ok(RustFieldType {
                     type_name: format!("Vec<{}>", item_type.type_name),
                     serializer,
@@ -1920,6 +5720,14 @@ fn get_rust_type_for_field(schema: &Schema, specs: &Specification) -> Result<Rus
                 type_name: String::from("u64"),
                 serializer: None,
             }),
+            Primitive::Null(_) => Ok(RustFieldType {
+                type_name: String::from("()"),
+                serializer: None,
+            }),
+            Primitive::Number(_) => Ok(RustFieldType {
+                type_name: String::from("f64"),
+                serializer: None,
+            }),
             Primitive::Object(_) => {
                 anyhow::bail!("Anonymous object types should not be used for properties");
             }
@@ -1986,10 +5794,291 @@ fn get_field_type_override(type_name: &str) -> Option<RustFieldType> {
     })
 }
 
-fn print_doc(doc: &str, indent_spaces: usize) {
+fn print_serde_with_adapter(out: &mut CodeWriter, adapter: &SerdeWithAdapter) {
+    print_doc(
+        out,
+        &format!(
+            "Adapter for using the `{}` serializer module with `serde_as`.",
+            adapter.module
+        ),
+        0,
+    );
+    writeln!(out, "pub struct {};", adapter.adapter);
+    writeln!(out);
+    writeln!(
+        out,
+        "impl serde_with::SerializeAs<{}> for {} {{",
+        adapter.type_name, adapter.adapter
+    );
+    writeln!(
+        out,
+        "    fn serialize_as<S: Serializer>(source: &{}, serializer: S) -> Result<S::Ok, S::Error> {{",
+        adapter.type_name
+    );
+    writeln!(
+        out,
+        "        {}::serialize(source, serializer)",
+        adapter.module
+    );
+    writeln!(out, "    }}");
+    writeln!(out, "}}");
+    writeln!(out);
+    writeln!(
+        out,
+        "impl<'de> serde_with::DeserializeAs<'de, {}> for {} {{",
+        adapter.type_name, adapter.adapter
+    );
+    writeln!(
+        out,
+        "    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<{}, D::Error> {{",
+        adapter.type_name
+    );
+    writeln!(out, "        {}::deserialize(deserializer)", adapter.module);
+    writeln!(out, "    }}");
+    writeln!(out, "}}");
+}
+
+fn print_lazy_field_element(out: &mut CodeWriter) {
+    print_doc(
+        out,
+        "A hex-encoded field element borrowed from the input, only parsed when requested.",
+        0,
+    );
+    writeln!(
+        out,
+        "#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]"
+    );
+    writeln!(out, "#[serde(transparent)]");
+    writeln!(
+        out,
+        "pub struct LazyFieldElement<'a>(#[serde(borrow)] Cow<'a, str>);"
+    );
+    writeln!(out);
+    writeln!(out, "impl<'a> LazyFieldElement<'a> {{");
+    writeln!(out, "    /// Gets the raw hex string.");
+    writeln!(out, "    pub fn as_str(&self) -> &str {{");
+    writeln!(out, "        &self.0");
+    writeln!(out, "    }}");
+    writeln!(out);
+    writeln!(out, "    /// Parses the hex string into a [FieldElement].");
+    writeln!(
+        out,
+        "    pub fn parse(&self) -> Result<FieldElement, FromStrError> {{"
+    );
+    writeln!(out, "        FieldElement::from_hex_be(&self.0)");
+    writeln!(out, "    }}");
+    writeln!(out, "}}");
+}
+
+fn print_builder_error(out: &mut CodeWriter) {
+    print_doc(
+        out,
+        "Error returned when building a type without setting all of its required fields.",
+        0,
+    );
+    writeln!(
+        out,
+        "#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]"
+    );
+    writeln!(
+        out,
+        "#[error(\"missing required field `{{field}}` when building `{{type_name}}`\")]"
+    );
+    writeln!(out, "pub struct BuilderError {{");
+    writeln!(out, "    /// Name of the type being built");
+    writeln!(out, "    pub type_name: &'static str,");
+    writeln!(out, "    /// Name of the missing field");
+    writeln!(out, "    pub field: &'static str,");
+    writeln!(out, "}}");
+}
+
+/// Prints the helper functions used by generated `Arbitrary` implementations for types that don't
+/// implement `Arbitrary` themselves, or whose valid values are narrower than what their Rust type
+/// allows.
+fn print_arbitrary_helpers(out: &mut CodeWriter) {
+    writeln!(out, "/// Generates a field element that's valid for all of `FELT`, `ADDRESS` and `STORAGE_KEY`.");
+    writeln!(out, "#[cfg(feature = \"arbitrary\")]");
+    writeln!(out, "fn arbitrary_field_element(");
+    writeln!(out, "    u: &mut arbitrary::Unstructured<'_>,");
+    writeln!(out, ") -> arbitrary::Result<FieldElement> {{");
+    writeln!(out, "    let mut bytes = u.arbitrary::<[u8; 32]>()?;");
+    writeln!(
+        out,
+        "    // Keeping the value under 2^251 makes it valid for `STORAGE_KEY` too."
+    );
+    writeln!(out, "    bytes[0] &= 0x07;");
+    writeln!(
+        out,
+        "    FieldElement::from_bytes_be(&bytes).map_err(|_| arbitrary::Error::IncorrectFormat)"
+    );
+    writeln!(out, "}}");
+    writeln!(out);
+    writeln!(out, "#[cfg(feature = \"arbitrary\")]");
+    writeln!(out, "fn arbitrary_vec<'a, T>(");
+    writeln!(out, "    u: &mut arbitrary::Unstructured<'a>,");
+    writeln!(
+        out,
+        "    mut generator: impl FnMut(&mut arbitrary::Unstructured<'a>) -> arbitrary::Result<T>,"
+    );
+    writeln!(out, ") -> arbitrary::Result<Vec<T>> {{");
+    writeln!(out, "    let mut items = vec![];");
+    writeln!(
+        out,
+        "    // Same as `Vec::arbitrary`, stopping once the data runs out instead of at a random length"
+    );
+    writeln!(out, "    while u.arbitrary()? {{");
+    writeln!(out, "        items.push(generator(u)?);");
+    writeln!(out, "    }}");
+    writeln!(out, "    Ok(items)");
+    writeln!(out, "}}");
+    writeln!(out);
+    writeln!(out, "#[cfg(feature = \"arbitrary\")]");
+    writeln!(out, "fn arbitrary_option<'a, T>(");
+    writeln!(out, "    u: &mut arbitrary::Unstructured<'a>,");
+    writeln!(
+        out,
+        "    generator: impl FnOnce(&mut arbitrary::Unstructured<'a>) -> arbitrary::Result<T>,"
+    );
+    writeln!(out, ") -> arbitrary::Result<Option<T>> {{");
+    writeln!(
+        out,
+        "    Ok(if u.arbitrary()? {{ Some(generator(u)?) }} else {{ None }})"
+    );
+    writeln!(out, "}}");
+}
+
+/// Builds the expression generating an arbitrary value of `type_name` from an `Unstructured`
+/// named `u`, falling back to `default_expr` when the type can implement `Arbitrary` on its own.
+fn to_arbitrary_expr(type_name: &str, default_expr: &str) -> String {
+    match to_arbitrary_generator(type_name) {
+        Some(generator) => match generator.strip_prefix("|u| ") {
+            Some(body) => format!("{body}?"),
+            None => format!("{generator}(u)?"),
+        },
+        None => default_expr.to_owned(),
+    }
+}
+
+/// Returns a callable generating an arbitrary value of `type_name`, or `None` if the type's own
+/// `Arbitrary` implementation can be used.
+fn to_arbitrary_generator(type_name: &str) -> Option<String> {
+    if type_name == "FieldElement" {
+        Some(String::from("arbitrary_field_element"))
+    } else if let Some(inner) = strip_generic(type_name, "Vec") {
+        to_arbitrary_generator(inner).map(|inner| format!("|u| arbitrary_vec(u, {inner})"))
+    } else if let Some(inner) = strip_generic(type_name, "Option") {
+        to_arbitrary_generator(inner).map(|inner| format!("|u| arbitrary_option(u, {inner})"))
+    } else {
+        None
+    }
+}
+
+fn strip_generic<'a>(type_name: &'a str, wrapper: &str) -> Option<&'a str> {
+    type_name
+        .strip_prefix(wrapper)
+        .and_then(|rest| rest.strip_prefix('<'))
+        .and_then(|rest| rest.strip_suffix('>'))
+}
+
+fn print_derives(out: &mut CodeWriter, derives: &[String]) {
+    writeln!(out, "#[derive({})]", derives.join(", "));
+}
+
+fn print_attributes(out: &mut CodeWriter, attributes: &[String]) {
+    for attribute in attributes.iter() {
+        writeln!(out, "{attribute}");
+    }
+}
+
+fn parse_field_path(value: &str) -> Result<(String, String)> {
+    match value.split_once('.') {
+        Some((type_name, field_name)) => Ok((type_name.to_owned(), field_name.to_owned())),
+        None => anyhow::bail!("expected `TYPE.FIELD`, got `{}`", value),
+    }
+}
+
+fn split_traits(traits: &str) -> Vec<String> {
+    traits
+        .split(',')
+        .map(|item| item.trim().to_owned())
+        .collect()
+}
+
+fn parse_type_option(value: &str) -> Result<(String, String)> {
+    match value.split_once('=') {
+        Some((type_name, option)) => Ok((type_name.trim().to_owned(), option.trim().to_owned())),
+        None => anyhow::bail!("expected `TYPE=VALUE`, got `{}`", value),
+    }
+}
+
+fn print_doc(out: &mut CodeWriter, doc: &str, indent_spaces: usize) {
     let prefix = format!("{}/// ", " ".repeat(indent_spaces));
-    for line in wrap_lines(doc, prefix.len()) {
-        println!("{prefix}{line}");
+    for line in wrap_lines(doc, prefix.len() + out.indent) {
+        writeln!(out, "{prefix}{line}");
+    }
+}
+
+/// Prints a `pattern => body,` match arm laid out like rustfmt does: on a single line if it fits,
+/// or else with `body` in a block. Bodies too long even for that must be calls, whose arguments
+/// are then broken onto their own line.
+fn print_match_arm(out: &mut CodeWriter, pattern: &str, body: &str, indent_spaces: usize) {
+    let indent = " ".repeat(indent_spaces);
+    let width = MAX_LINE_LENGTH - out.indent;
+
+    // rustfmt keeps two columns to spare after bodies ending with `?`, and after calls left on
+    // the line of the pattern
+    let line = format!("{indent}{pattern} => {body},");
+    if line.len() + 2 * usize::from(body.ends_with('?')) <= width {
+        writeln!(out, "{line}");
+        return;
+    }
+    if indent_spaces + 4 + body.len() <= width {
+        writeln!(out, "{indent}{pattern} => {{");
+        writeln!(out, "{indent}    {body}");
+        writeln!(out, "{indent}}}");
+        return;
+    }
+
+    let (callee, args) = body
+        .strip_suffix(')')
+        .and_then(|body| body.split_once('('))
+        .expect("match arm bodies too long for a block must be calls");
+    let head = format!("{indent}{pattern} => {callee}(");
+    if head.len() + 2 <= width {
+        writeln!(out, "{head}");
+        writeln!(out, "{indent}    {args},");
+        writeln!(out, "{indent}),");
+    } else {
+        writeln!(out, "{indent}{pattern} => {{");
+        writeln!(out, "{indent}    {callee}(");
+        writeln!(out, "{indent}        {args},");
+        writeln!(out, "{indent}    )");
+        writeln!(out, "{indent}}}");
+    }
+}
+
+impl CodeWriter {
+    fn new(indent: usize) -> Self {
+        Self {
+            code: String::new(),
+            indent,
+        }
+    }
+
+    /// Appends formatted code, which makes the writer usable with `write!` and `writeln!`.
+    fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) {
+        std::fmt::Write::write_fmt(&mut self.code, args).expect("writing to a String can't fail");
+    }
+
+    /// Prints the code with each line indented to the module, leaving empty lines empty.
+    fn print(&self) {
+        for line in self.code.lines() {
+            if line.is_empty() {
+                println!();
+            } else {
+                println!("{:indent$}{line}", "", indent = self.indent);
+            }
+        }
     }
 }
 
@@ -2044,6 +6133,35 @@ fn to_starknet_rs_name(name: &str) -> String {
     }
 }
 
+/// Prefix of the names of types generated for a method, e.g. `GetBlockWithTxHashes` for
+/// `starknet_getBlockWithTxHashes`.
+fn to_method_type_prefix(method_name: &str) -> String {
+    to_starknet_rs_name(&camel_to_snake_case(
+        method_name.trim_start_matches("starknet_"),
+    ))
+}
+
+/// Names a `oneOf` variant after the words of its schema name that aren't part of the enum's
+/// schema name, e.g. `INVOKE_TXN_V1` becomes `V1` inside `INVOKE_TXN`.
+fn to_one_of_variant_name(enum_name: &str, variant_name: &str) -> String {
+    let mut enum_words = enum_name.split('_').collect::<Vec<_>>();
+
+    let variant_words = variant_name
+        .split('_')
+        .filter(
+            |word| match enum_words.iter().position(|item| item == word) {
+                Some(ind) => {
+                    enum_words.remove(ind);
+                    false
+                }
+                None => true,
+            },
+        )
+        .collect::<Vec<_>>();
+
+    to_pascal_case(&variant_words.join("_"))
+}
+
 fn to_rust_field_name(name: &str) -> String {
     let all_upper_letters_regex = Regex::new("^[A-Z]+$").unwrap();
 
@@ -2154,3 +6272,478 @@ fn escape_name(name: &str) -> &str {
         name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE64_SCHEMA: &str = r#"{"type":"string","description":"A base64 representation"}"#;
+
+    /// Main specification of a shipped version, without the write methods.
+    fn load_spec(version: &str) -> Specification {
+        let raw_spec = match version {
+            "0.1.0" => include_str!("./specs/0.1.0/starknet_api_openrpc.json"),
+            "0.2.1" => include_str!("./specs/0.2.1/starknet_api_openrpc.json"),
+            "0.3.0" => include_str!("./specs/0.3.0/starknet_api_openrpc.json"),
+            _ => panic!("unknown spec version: {version}"),
+        };
+        serde_json::from_str(raw_spec).unwrap()
+    }
+
+    /// Required field without any serde customization.
+    fn field(name: &str, type_name: &str) -> RustField {
+        RustField {
+            description: None,
+            name: name.to_owned(),
+            optional: false,
+            fixed: None,
+            arc_wrap: false,
+            type_name: type_name.to_owned(),
+            serde_rename: None,
+            serde_faltten: false,
+            serializer: None,
+            nested_ref: false,
+            borrowed_type_name: None,
+            nullable: false,
+            serialize_none: false,
+            serde_default: false,
+            flattened_from: vec![],
+        }
+    }
+
+    /// `oneOf` enum with `(name, type_name)` variants.
+    fn one_of_type(name: &str, variants: &[(&str, &str)]) -> RustType {
+        RustType {
+            title: None,
+            description: None,
+            name: name.to_owned(),
+            extra_derives: vec![],
+            removed_derives: vec![],
+            extra_attributes: vec![],
+            content: RustTypeKind::OneOf(RustOneOf {
+                extra_ref_type: false,
+                extra_borrowed_type: false,
+                variants: variants
+                    .iter()
+                    .map(|(name, type_name)| RustOneOfVariant {
+                        description: None,
+                        name: String::from(*name),
+                        type_name: String::from(*type_name),
+                        literal: None,
+                        legal_value: None,
+                        ref_type_name: None,
+                        borrowed_type_name: None,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+
+    fn field_serde_as(schema: &str) -> (String, String) {
+        let specs = load_spec("0.3.0");
+        let schema: Schema = serde_json::from_str(schema).unwrap();
+
+        let field_type = get_rust_type_for_field(&schema, &specs).unwrap();
+        match field_type.serializer {
+            Some(SerializerOverride::SerdeAs(serde_as)) => (field_type.type_name, serde_as),
+            other => panic!("unexpected serializer: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn serde_with_inside_arrays() {
+        let array = format!(r#"{{"type":"array","items":{BASE64_SCHEMA}}}"#);
+        let nested = format!(r#"{{"type":"array","items":{array}}}"#);
+
+        assert_eq!(
+            field_serde_as(&array),
+            (String::from("Vec<Vec<u8>>"), String::from("Vec<Base64As>"))
+        );
+        assert_eq!(
+            field_serde_as(&nested),
+            (
+                String::from("Vec<Vec<Vec<u8>>>"),
+                String::from("Vec<Vec<Base64As>>")
+            )
+        );
+    }
+
+    fn object_field_lines(properties: &str, required: &str) -> Vec<String> {
+        let specs = load_spec("0.3.0");
+        let schema: Schema = serde_json::from_str(&format!(
+            r#"{{"type":"object","properties":{properties},"required":{required}}}"#
+        ))
+        .unwrap();
+
+        let mut fields = vec![];
+        get_schema_fields(&schema, &specs, &mut fields, &FlattenOption::All).unwrap();
+
+        fields
+            .iter()
+            .flat_map(|field| field.def_lines(0, true, false, false))
+            .collect()
+    }
+
+    #[test]
+    fn nullable_type_arrays() {
+        let schema: Schema =
+            serde_json::from_str(r#"{"type":["integer","null"],"description":"Count"}"#).unwrap();
+
+        match schema {
+            Schema::OneOf(one_of) => {
+                assert_eq!(one_of.description.as_deref(), Some("Count"));
+                assert!(matches!(
+                    one_of.nullable_variant(),
+                    Some(Schema::Primitive(Primitive::Integer(_)))
+                ));
+            }
+            _ => panic!("unexpected schema: {schema:?}"),
+        }
+    }
+
+    #[test]
+    fn optional_and_nullable_fields() {
+        assert_eq!(
+            object_field_lines(r#"{"a":{"type":"string"}}"#, "[]"),
+            [
+                "#[serde(skip_serializing_if = \"Option::is_none\")]",
+                "pub a: Option<String>,"
+            ]
+        );
+        assert_eq!(
+            object_field_lines(r#"{"a":{"type":["number","null"]}}"#, r#"["a"]"#),
+            ["pub a: Option<f64>,"]
+        );
+        assert_eq!(
+            object_field_lines(
+                r##"{"a":{"oneOf":[{"$ref":"#/components/schemas/FELT"},{"type":"null"}]}}"##,
+                r#"["a"]"#
+            ),
+            [
+                "#[serde_as(as = \"Option<UfeHex>\")]",
+                "pub a: Option<FieldElement>,"
+            ]
+        );
+        assert_eq!(
+            object_field_lines(r#"{"a":{"type":["string","null"]}}"#, "[]"),
+            [
+                "#[serde(default, skip_serializing_if = \"Option::is_none\")]",
+                "#[serde(deserialize_with = \"serde_with::rust::double_option::deserialize\")]",
+                "pub a: Option<Option<String>>,"
+            ]
+        );
+    }
+
+    #[test]
+    fn optional_field_policies() {
+        let mut field = RustField {
+            optional: true,
+            serialize_none: true,
+            ..field("a", "Option<u64>")
+        };
+        assert_eq!(
+            field.def_lines(0, true, false, false),
+            ["pub a: Option<u64>,"]
+        );
+
+        field.serde_default = true;
+        assert_eq!(
+            field.def_lines(0, true, false, false),
+            ["#[serde(default)]", "pub a: Option<u64>,"]
+        );
+
+        field.serialize_none = false;
+        assert_eq!(
+            field.def_lines(0, true, false, false),
+            [
+                "#[serde(default, skip_serializing_if = \"Option::is_none\")]",
+                "pub a: Option<u64>,"
+            ]
+        );
+    }
+
+    #[test]
+    fn model_ref_type_names() {
+        let nested = |type_name: &str| RustField {
+            nested_ref: true,
+            ..field("a", type_name)
+        };
+
+        assert_eq!(field("a", "String").model_ref_type_name(), "Cow<'a, str>");
+        assert_eq!(
+            field("a", "Vec<Event>").model_ref_type_name(),
+            "Cow<'a, [Event]>"
+        );
+        assert_eq!(
+            field("a", "Option<String>").model_ref_type_name(),
+            "Option<&'a str>"
+        );
+        assert_eq!(
+            field("a", "Option<Vec<Event>>").model_ref_type_name(),
+            "Option<&'a [Event]>"
+        );
+        assert_eq!(nested("Event").model_ref_type_name(), "EventRef<'a>");
+        assert_eq!(
+            nested("Option<Event>").model_ref_type_name(),
+            "Option<EventRef<'a>>"
+        );
+        assert_eq!(
+            nested("Option<Event>").ref_type_name(Some("'a")),
+            "&'a Option<EventRef<'a>>"
+        );
+    }
+
+    #[test]
+    fn flatten_inference_considers_methods() {
+        let specs = load_spec("0.3.0");
+
+        let flatten_only = get_flatten_only_schemas(&specs, &FlattenOption::All);
+
+        // Only used as `allOf` fragments
+        assert!(flatten_only.contains(&String::from("COMMON_TXN_PROPERTIES")));
+        // Also used as param of `starknet_call`
+        assert!(!flatten_only.contains(&String::from("FUNCTION_CALL")));
+        // Also used as result of `starknet_getStateUpdate`
+        assert!(!flatten_only.contains(&String::from("PENDING_STATE_UPDATE")));
+    }
+
+    #[test]
+    fn fixed_fields_inferred_from_single_values() {
+        let specs = load_spec("0.3.0");
+        let schema: Schema = serde_json::from_str(
+            r#"{"type":"object","properties":{"type":{"type":"string","enum":["INVOKE"]},"kind":{"type":"string","const":"call"},"status":{"type":"string","enum":["A"]}},"required":["type","kind"]}"#,
+        )
+        .unwrap();
+
+        let mut fields = vec![];
+        get_schema_fields(&schema, &specs, &mut fields, &FlattenOption::All).unwrap();
+
+        let fixed_values = fields
+            .iter()
+            .map(|field| field.fixed.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(fixed_values, [Some("\"INVOKE\""), Some("\"call\""), None]);
+    }
+
+    #[test]
+    fn arbitrary_rejects_unenforced_patterns() {
+        let mut specs = load_spec("0.3.0");
+        check_arbitrary_patterns(&specs, &[]).unwrap();
+
+        // Base64 strings map to `Vec<u8>` so their patterns don't matter
+        let base64: Schema = serde_json::from_str(
+            r#"{"type":"string","description":"A base64 representation","pattern":"^[A-Za-z0-9+/]*$"}"#,
+        )
+        .unwrap();
+        specs
+            .components
+            .schemas
+            .insert(String::from("PROGRAM"), base64);
+        check_arbitrary_patterns(&specs, &[]).unwrap();
+
+        let patterned: Schema = serde_json::from_str(
+            r#"{"type":"object","properties":{"name":{"type":"string","pattern":"^[a-z]+$"}}}"#,
+        )
+        .unwrap();
+        specs
+            .components
+            .schemas
+            .insert(String::from("LABEL"), patterned);
+        assert!(check_arbitrary_patterns(&specs, &[]).is_err());
+        check_arbitrary_patterns(&specs, &[String::from("LABEL")]).unwrap();
+    }
+
+    #[test]
+    fn one_of_variant_names() {
+        assert_eq!(to_one_of_variant_name("TXN", "L1_HANDLER_TXN"), "L1Handler");
+        assert_eq!(to_one_of_variant_name("INVOKE_TXN", "INVOKE_TXN_V1"), "V1");
+        assert_eq!(
+            to_one_of_variant_name("BROADCASTED_TXN", "BROADCASTED_DEPLOY_ACCOUNT_TXN"),
+            "DeployAccount"
+        );
+        assert_eq!(to_one_of_variant_name("TXN_RECEIPT", "TXN_RECEIPT"), "");
+    }
+
+    #[test]
+    fn one_of_conversions_are_transitive() {
+        let types = [
+            one_of_type(
+                "Transaction",
+                &[("Invoke", "InvokeTransaction"), ("Deploy", "Deploy")],
+            ),
+            one_of_type("InvokeTransaction", &[("V0", "InvokeV0"), ("V1", "Deploy")]),
+        ];
+
+        let conversions = resolve_one_of_conversions(&types)
+            .into_iter()
+            .map(|item| {
+                let path = item
+                    .path
+                    .iter()
+                    .map(|(_, variant)| variant.as_str())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                format!("{}<-{}:{}", item.enum_name, item.type_name, path)
+            })
+            .collect::<Vec<_>>();
+
+        // `Deploy` is nested twice inside `Transaction`
+        assert_eq!(
+            conversions,
+            [
+                "Transaction<-InvokeTransaction:Invoke",
+                "Transaction<-InvokeV0:Invoke/V0",
+                "InvokeTransaction<-InvokeV0:V0",
+                "InvokeTransaction<-Deploy:V1",
+            ]
+        );
+    }
+
+    #[test]
+    fn one_of_enums_need_distinguishable_variants() {
+        let specs = load_spec("0.1.0");
+        let derive_options = DeriveOptions {
+            derives: vec![],
+            removed_derives: vec![],
+            attributes: vec![],
+            types: vec![],
+        };
+
+        let mut result = resolve_types(
+            &specs,
+            &FlattenOption::Selected(vec![]),
+            &[],
+            &FixedFieldsOptions {
+                fixed_field_types: vec![],
+            },
+            &ArcWrappingOptions {
+                arc_wrapped_types: vec![],
+            },
+            &derive_options,
+        )
+        .unwrap();
+        resolve_one_of_enums(&specs, &mut result, &derive_options).unwrap();
+
+        // Without fixed fields, deploy receipts have all fields of declare receipts
+        assert!(result
+            .not_implemented
+            .contains(&String::from("TXN_RECEIPT")));
+        assert!(result
+            .model_types
+            .iter()
+            .any(|rust_type| rust_type.name == "Transaction"));
+    }
+
+    #[test]
+    fn param_structure_defaults_to_either() {
+        let specs = load_spec("0.3.0");
+        let param_structure = |name: &str| {
+            specs
+                .methods
+                .iter()
+                .find(|method| method.name == name)
+                .unwrap()
+                .param_structure
+        };
+
+        assert_eq!(
+            param_structure("starknet_getTransactionByHash"),
+            ParamStructure::ByName
+        );
+        assert_eq!(param_structure("starknet_getNonce"), ParamStructure::Either);
+    }
+
+    #[test]
+    fn envelope_result_types() {
+        let specs = load_spec("0.3.0");
+        let envelopes = JsonRpcEnvelopes::resolve(&specs).unwrap();
+        let method = |name: &str| {
+            envelopes
+                .methods
+                .iter()
+                .find(|method| method.name == name)
+                .unwrap()
+        };
+
+        let class_hash_at = method("starknet_getClassHashAt");
+        assert_eq!(class_hash_at.variant_name, "GetClassHashAt");
+        assert_eq!(class_hash_at.request_type, "GetClassHashAtRequest");
+        assert_eq!(class_hash_at.result_type.type_name, "FieldElement");
+        assert!(matches!(
+            &class_hash_at.result_type.serializer,
+            Some(SerializerOverride::SerdeAs(serde_as)) if serde_as == "UfeHex"
+        ));
+
+        let syncing = method("starknet_syncing");
+        assert_eq!(syncing.result_type.type_name, "SyncingResponse");
+        assert!(syncing.result_type.serializer.is_none());
+    }
+
+    fn render_negotiation(versions: &[(&'static str, &Specification)]) -> String {
+        let mut out = CodeWriter::new(0);
+        VersionNegotiation::resolve(versions, &[]).render_stdout(&mut out);
+        out.code
+    }
+
+    #[test]
+    fn negotiation_reports_undetectable_versions() {
+        let specs = load_spec("0.3.0");
+
+        let code = render_negotiation(&[("v0_2", &specs), ("v0_3", &specs)]);
+        assert!(code.contains(
+            "only detected\n// through `starknet_specVersion` and the newer version is picked \
+            otherwise:\n// - `v0_2`\n"
+        ));
+    }
+
+    #[test]
+    fn negotiation_only_probes_read_methods() {
+        let specs = load_spec("0.3.0");
+        let mut extended = specs.clone();
+        for name in ["starknet_addTestTransaction", "starknet_getTest"] {
+            let mut method = specs.methods[0].clone();
+            method.name = String::from(name);
+            extended.methods.push(method);
+        }
+
+        let mut out = CodeWriter::new(0);
+        VersionNegotiation::resolve(
+            &[("v0_2", &specs), ("v0_3", &extended)],
+            &[String::from("starknet_addTestTransaction")],
+        )
+        .render_stdout(&mut out);
+        assert!(out.code.contains("\n    \"starknet_getTest\",\n"));
+        assert!(!out.code.contains("starknet_addTestTransaction"));
+    }
+
+    #[test]
+    fn negotiation_falls_back_with_many_probed_methods() {
+        let specs = load_spec("0.3.0");
+        let mut extended = specs.clone();
+        for ind in 0..64 {
+            let mut method = specs.methods[0].clone();
+            method.name = format!("test_method{ind}");
+            extended.methods.push(method);
+        }
+
+        let code = render_negotiation(&[("v0_2", &specs), ("v0_3", &extended)]);
+        assert!(code.contains("_ => Err(VersionNegotiationError::UnknownVersion),"));
+    }
+
+    #[test]
+    fn serde_with_inside_options() {
+        let with = SerializerOverride::Serde(String::from("base64"));
+
+        assert!(matches!(
+            with.to_optional().unwrap(),
+            SerializerOverride::SerdeAs(serde_as) if serde_as == "Option<Base64As>"
+        ));
+        assert!(matches!(
+            with.to_array().unwrap().to_optional().unwrap(),
+            SerializerOverride::SerdeAs(serde_as) if serde_as == "Option<Vec<Base64As>>"
+        ));
+        assert!(SerializerOverride::Serde(String::from("unknown"))
+            .to_optional()
+            .is_err());
+    }
+}
@@ -1,11 +1,18 @@
 use indexmap::IndexMap;
-use serde::Deserialize;
+use serde::{de::Error as DeError, Deserialize, Deserializer};
+use serde_json::Value;
+
+// Fields marked `#[allow(dead_code)]` are never read by the generator, but have to be declared for
+// `deny_unknown_fields` to accept the specs. Derived `Debug` and `Clone` impls don't count as
+// reads, so rustc would warn on them otherwise.
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Specification {
+    #[allow(dead_code)]
     pub openrpc: String,
     pub info: Info,
+    #[allow(dead_code)]
     pub servers: Vec<String>,
     pub methods: Vec<Method>,
     pub components: Components,
@@ -15,7 +22,9 @@ pub struct Specification {
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Info {
     pub version: String,
+    #[allow(dead_code)]
     pub title: String,
+    #[allow(dead_code)]
     pub license: Empty,
 }
 
@@ -24,16 +33,29 @@ pub struct Info {
 pub struct Method {
     pub name: String,
     pub summary: String,
+    #[allow(dead_code)]
     pub description: Option<String>,
-    pub param_structure: Option<String>,
+    #[serde(default)]
+    pub param_structure: ParamStructure,
     pub params: Vec<Param>,
     pub result: MethodResult,
     pub errors: Option<Vec<Reference>>,
 }
 
+/// How params are passed to a method. Params can be passed either way if not specified.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ParamStructure {
+    ByPosition,
+    ByName,
+    #[default]
+    Either,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Components {
+    #[allow(dead_code)]
     pub content_descriptors: Empty,
     pub schemas: IndexMap<String, Schema>,
     pub errors: IndexMap<String, ErrorType>,
@@ -48,7 +70,10 @@ pub struct Empty {}
 pub struct Param {
     pub name: String,
     pub description: Option<String>,
+    #[allow(dead_code)]
     pub summary: Option<String>,
+    /// Params are optional when not specified, as per OpenRPC.
+    #[serde(default)]
     pub required: bool,
     pub schema: Schema,
 }
@@ -56,14 +81,15 @@ pub struct Param {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct MethodResult {
+    #[allow(dead_code)]
     pub name: String,
     pub description: Option<String>,
     pub schema: Schema,
+    #[allow(dead_code)]
     pub summary: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone)]
 pub enum Schema {
     Ref(Reference),
     OneOf(OneOf),
@@ -71,10 +97,21 @@ pub enum Schema {
     Primitive(Primitive),
 }
 
+/// [Schema] as written in the spec, before resolving `"type"` arrays.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawSchema {
+    Ref(Reference),
+    OneOf(OneOf),
+    AllOf(AllOf),
+    Primitive(Primitive),
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Reference {
     pub title: Option<String>,
+    #[allow(dead_code)]
     #[serde(rename = "$comment")]
     pub comment: Option<String>,
     pub description: Option<String>,
@@ -104,6 +141,8 @@ pub enum Primitive {
     Array(ArrayPrimitive),
     Boolean(BooleanPrimitive),
     Integer(IntegerPrimitive),
+    Null(NullPrimitive),
+    Number(NumberPrimitive),
     Object(ObjectPrimitive),
     String(StringPrimitive),
 }
@@ -128,9 +167,26 @@ pub struct BooleanPrimitive {
 pub struct IntegerPrimitive {
     pub title: Option<String>,
     pub description: Option<String>,
+    #[allow(dead_code)]
     pub minimum: Option<i32>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct NullPrimitive {
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct NumberPrimitive {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[allow(dead_code)]
+    pub minimum: Option<f64>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ObjectPrimitive {
@@ -145,10 +201,12 @@ pub struct ObjectPrimitive {
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct StringPrimitive {
     pub title: Option<String>,
+    #[allow(dead_code)]
     #[serde(rename = "$comment")]
     pub comment: Option<String>,
     pub description: Option<String>,
     pub r#enum: Option<Vec<String>>,
+    pub r#const: Option<String>,
     pub pattern: Option<String>,
 }
 
@@ -156,7 +214,7 @@ pub struct StringPrimitive {
 #[serde(untagged)]
 pub enum ErrorType {
     Error(Error),
-    Reference(Reference),
+    Reference(#[allow(dead_code)] Reference),
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -167,7 +225,52 @@ pub struct Error {
     pub data: Option<Schema>,
 }
 
+impl<'de> Deserialize<'de> for Schema {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Going through `Value` relies on the `preserve_order` feature of `serde_json` to keep the
+        // properties of object schemas in order.
+        let value = Value::deserialize(deserializer)?;
+
+        // `"type": [...]` is the same as a `oneOf` over each type, which is how nullable types are
+        // usually written. Only the non-null variants keep the other keywords.
+        if let Some(types) = value.get("type").and_then(|value| value.as_array()) {
+            let variants = types
+                .iter()
+                .map(|type_name| {
+                    let mut variant = if type_name == "null" {
+                        Value::Object(Default::default())
+                    } else {
+                        value.clone()
+                    };
+                    variant["type"] = type_name.clone();
+                    serde_json::from_value::<Schema>(variant)
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(DeError::custom)?;
+
+            return Ok(Self::OneOf(OneOf {
+                title: value["title"].as_str().map(|value| value.to_owned()),
+                description: value["description"].as_str().map(|value| value.to_owned()),
+                one_of: variants,
+            }));
+        }
+
+        Ok(
+            match serde_json::from_value::<RawSchema>(value).map_err(DeError::custom)? {
+                RawSchema::Ref(schema) => Self::Ref(schema),
+                RawSchema::OneOf(schema) => Self::OneOf(schema),
+                RawSchema::AllOf(schema) => Self::AllOf(schema),
+                RawSchema::Primitive(schema) => Self::Primitive(schema),
+            },
+        )
+    }
+}
+
 impl Schema {
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Primitive(Primitive::Null(_)))
+    }
+
     pub fn title(&self) -> Option<&String> {
         match self {
             Self::Ref(schema) => schema.title.as_ref(),
@@ -202,6 +305,8 @@ impl Primitive {
             Self::Array(schema) => schema.title.as_ref(),
             Self::Boolean(schema) => schema.title.as_ref(),
             Self::Integer(schema) => schema.title.as_ref(),
+            Self::Null(schema) => schema.title.as_ref(),
+            Self::Number(schema) => schema.title.as_ref(),
             Self::Object(schema) => schema.title.as_ref(),
             Self::String(schema) => schema.title.as_ref(),
         }
@@ -212,6 +317,8 @@ impl Primitive {
             Self::Array(schema) => schema.description.as_ref(),
             Self::Boolean(schema) => schema.description.as_ref(),
             Self::Integer(schema) => schema.description.as_ref(),
+            Self::Null(schema) => schema.description.as_ref(),
+            Self::Number(schema) => schema.description.as_ref(),
             Self::Object(schema) => schema.description.as_ref(),
             Self::String(schema) => schema.description.as_ref(),
         }
@@ -222,12 +329,37 @@ impl Primitive {
             Self::Array(_) => None,
             Self::Boolean(_) => None,
             Self::Integer(_) => None,
+            Self::Null(_) => None,
+            Self::Number(_) => None,
             Self::Object(schema) => schema.summary.as_ref(),
             Self::String(_) => None,
         }
     }
 }
 
+impl StringPrimitive {
+    /// Gets the only value allowed, if the schema is a `const` or single-value `enum`.
+    pub fn single_value(&self) -> Option<&str> {
+        match (&self.r#const, &self.r#enum) {
+            (Some(value), _) => Some(value),
+            (None, Some(variants)) if variants.len() == 1 => Some(&variants[0]),
+            _ => None,
+        }
+    }
+}
+
+impl OneOf {
+    /// Gets the only non-null variant if this `oneOf` is a nullable version of another schema.
+    pub fn nullable_variant(&self) -> Option<&Schema> {
+        match self.one_of.as_slice() {
+            [variant, null] | [null, variant] if null.is_null() && !variant.is_null() => {
+                Some(variant)
+            }
+            _ => None,
+        }
+    }
+}
+
 impl Reference {
     pub fn name(&self) -> &str {
         match self.ref_field.rfind('/') {
@@ -0,0 +1,59 @@
+// Each test only uses some of the helpers
+#![allow(dead_code)]
+
+use std::process::{Command, ExitStatus};
+
+pub const SPEC_VERSIONS: [&str; 3] = ["0.1.0", "0.2.1", "0.3.0"];
+
+pub struct Output {
+    pub stdout: String,
+    pub stderr: String,
+    pub status: ExitStatus,
+}
+
+/// Runs the codegen binary with `args`, ignoring the `SPEC` environment variable.
+pub fn run(args: &[&str]) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_starknet-jsonrpc-codegen"))
+        .args(args)
+        .env_remove("SPEC")
+        .output()
+        .expect("failed to run codegen binary");
+
+    Output {
+        stdout: String::from_utf8(output.stdout).expect("stdout is not valid UTF-8"),
+        stderr: String::from_utf8(output.stderr).expect("stderr is not valid UTF-8"),
+        status: output.status,
+    }
+}
+
+/// Runs the codegen binary with `args` and returns the generated code, with the line containing
+/// the Git commit hash normalized so that output doesn't change from commit to commit.
+pub fn generate(args: &[&str]) -> String {
+    let Output {
+        stdout,
+        stderr,
+        status,
+    } = run(args);
+
+    assert!(
+        status.success(),
+        "codegen failed with args {:?}:\n{}",
+        args,
+        stderr
+    );
+
+    let mut lines = vec![];
+    let mut last_line = "";
+    for line in stdout.lines() {
+        if last_line == "// Code generated with version:" {
+            lines.push("//     <commit>");
+        } else {
+            lines.push(line);
+        }
+        last_line = line;
+    }
+
+    let mut normalized = lines.join("\n");
+    normalized.push('\n');
+    normalized
+}
@@ -0,0 +1,595 @@
+//! Checks that generated code compiles against the stub `starknet_core` crate in `tests/stub`.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+mod common;
+
+use common::{generate, SPEC_VERSIONS};
+
+/// Small spec covering cases the shipped specs don't have, used with `--spec-file`.
+const SYNTHETIC_SPEC: &str = "tests/specs/synthetic.json";
+
+fn copy_dir(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).unwrap();
+
+    for entry in std::fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &target);
+        } else {
+            std::fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+/// Copies the stub crate with code generated from `args`, returning the crate directory.
+fn stub_crate(name: &str, args: &[&str]) -> PathBuf {
+    let stub_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("stub");
+    let crate_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("stub-{name}"));
+
+    copy_dir(&stub_dir, &crate_dir);
+    std::fs::write(
+        crate_dir.join("src").join("types").join("codegen.rs"),
+        generate(args),
+    )
+    .unwrap();
+
+    crate_dir
+}
+
+fn assert_cargo_succeeds(crate_dir: &Path, command: &[&str], args: &[&str]) {
+    let output = Command::new(std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo")))
+        .args(command)
+        .args(["--quiet", "--all-features", "--manifest-path"])
+        .arg(crate_dir.join("Cargo.toml"))
+        // Sharing the target dir avoids rebuilding dependencies for each case
+        .env(
+            "CARGO_TARGET_DIR",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("stub-target"),
+        )
+        .output()
+        .expect("failed to run cargo");
+
+    assert!(
+        output.status.success(),
+        "cargo {:?} failed on code generated with args {:?}:\n{}{}",
+        command,
+        args,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn assert_compiles(name: &str, args: &[&str]) {
+    assert_cargo_succeeds(&stub_crate(name, args), &["check"], args);
+}
+
+/// Runs `tests/stub_tests/{test}.rs` as an integration test of the stub crate, along with the
+/// helper modules shared by all stub tests.
+fn assert_stub_tests_pass(test: &str, args: &[&str]) {
+    let crate_dir = stub_crate(&format!("tests-{test}"), args);
+    let stub_tests_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("stub_tests");
+
+    std::fs::create_dir_all(crate_dir.join("tests")).unwrap();
+    std::fs::copy(
+        stub_tests_dir.join(format!("{test}.rs")),
+        crate_dir.join("tests").join(format!("{test}.rs")),
+    )
+    .unwrap();
+    for helpers in ["common", "transport"] {
+        copy_dir(
+            &stub_tests_dir.join(helpers),
+            &crate_dir.join("tests").join(helpers),
+        );
+    }
+
+    assert_cargo_succeeds(&crate_dir, &["test", "--test", test], args);
+}
+
+#[test]
+fn generated_code_compiles() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(version, &["--spec", version]);
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_arbitrary() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-arbitrary"),
+            &["--spec", version, "--arbitrary"],
+        );
+    }
+}
+
+#[test]
+fn arbitrary_values_round_trip() {
+    assert_stub_tests_pass(
+        "arbitrary",
+        &[
+            "--spec",
+            "0.1.0",
+            "--response-types",
+            "--arbitrary",
+            "--derive",
+            "PartialEq",
+        ],
+    );
+}
+
+#[test]
+fn generated_code_compiles_with_extra_derives() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-derives"),
+            &[
+                "--spec",
+                version,
+                "--derive",
+                "PartialEq,Eq,Hash",
+                "--type-attribute",
+                "FeeEstimate=#[non_exhaustive]",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_default_derives() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-default-derives"),
+            &[
+                "--spec",
+                version,
+                "--oneof-enums",
+                "--arbitrary",
+                "--derive",
+                "Default",
+                "--default-variant",
+                "BlockTag=Latest",
+                "--no-derive",
+                "Copy",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_builders() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-builders"),
+            &["--spec", version, "--builders"],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_ref_types() {
+    for version in SPEC_VERSIONS {
+        let mut args = vec!["--spec", version];
+        for ref_type in [
+            "StateUpdate",
+            "StateDiff",
+            "EmittedEvent",
+            "EventFilterWithPage",
+        ] {
+            args.push("--ref-type");
+            args.push(ref_type);
+        }
+        if version != "0.1.0" {
+            args.push("--ref-type");
+            args.push("BroadcastedInvokeTransactionV1");
+        }
+
+        assert_compiles(&format!("{version}-ref-types"), &args);
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_one_of_ref_types() {
+    for version in SPEC_VERSIONS.iter().filter(|version| **version != "0.1.0") {
+        assert_compiles(
+            &format!("{version}-one-of-ref-types"),
+            &[
+                "--spec",
+                version,
+                "--oneof-enums",
+                "--ref-type",
+                "BroadcastedInvokeTransaction",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_borrowed_types() {
+    for version in SPEC_VERSIONS {
+        let mut args = vec!["--spec", version, "--oneof-enums"];
+        for borrowed_type in [
+            "BlockWithTxHashes",
+            "BlockWithTxs",
+            "StateUpdate",
+            "EmittedEvent",
+        ] {
+            args.push("--borrowed-type");
+            args.push(borrowed_type);
+        }
+        if version != "0.1.0" {
+            args.push("--borrowed-type");
+            args.push("InvokeTransactionReceipt");
+        }
+
+        assert_compiles(&format!("{version}-borrowed-types"), &args);
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_optional_field_policies() {
+    for version in SPEC_VERSIONS {
+        let args = vec![
+            "--spec",
+            version,
+            "--none-serialization",
+            "null",
+            "--field-none-serialization",
+            "EventFilter.to_block=omit",
+            "--field-serde-default",
+            "EventFilter.from_block=true",
+            "--ref-type",
+            "EventFilter",
+            "--serde-default",
+        ];
+
+        assert_compiles(&format!("{version}-optional-fields"), &args);
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_accessor_traits() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-accessor-traits"),
+            &["--spec", version, "--accessor-traits"],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_oneof_enums() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-oneof-enums"),
+            &[
+                "--spec",
+                version,
+                "--oneof-enums",
+                "--accessor-traits",
+                "--arbitrary",
+                "--derive",
+                "PartialEq,Eq",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_methods() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-methods"),
+            &[
+                "--spec",
+                version,
+                "--methods",
+                "starknet_call,starknet_getEvents,starknet_getNonce",
+                "--builders",
+                "--arbitrary",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_response_types() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-response-types"),
+            &[
+                "--spec",
+                version,
+                "--response-types",
+                "--builders",
+                "--arbitrary",
+                "--derive",
+                "PartialEq,Eq",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_jsonrpc_envelopes() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-jsonrpc-envelopes"),
+            &[
+                "--spec",
+                version,
+                "--response-types",
+                "--jsonrpc-envelopes",
+                "--oneof-enums",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_client() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-client"),
+            &[
+                "--spec",
+                version,
+                "--response-types",
+                "--jsonrpc-envelopes",
+                "--client",
+                "--ref-type",
+                "EventFilterWithPage",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_mock_provider() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-mock-provider"),
+            &[
+                "--spec",
+                version,
+                "--response-types",
+                "--jsonrpc-envelopes",
+                "--client",
+                "--mock-provider",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_record_replay() {
+    for version in SPEC_VERSIONS {
+        assert_compiles(
+            &format!("{version}-record-replay"),
+            &[
+                "--spec",
+                version,
+                "--response-types",
+                "--jsonrpc-envelopes",
+                "--client",
+                "--record-replay",
+            ],
+        );
+    }
+}
+
+#[test]
+fn generated_code_compiles_with_all_specs() {
+    assert_compiles(
+        "all-specs",
+        &[
+            "--all-specs",
+            "--version-conversions",
+            "--builders",
+            "--arbitrary",
+            "--oneof-enums",
+            "--accessor-traits",
+            "--derive",
+            "PartialEq,Eq",
+            "--borrowed-type",
+            "BlockWithTxHashes",
+            "--ref-type",
+            "EmittedEvent",
+        ],
+    );
+    assert_compiles(
+        "multiple-specs-client",
+        &[
+            "--spec",
+            "0.2.1,0.3.0",
+            "--response-types",
+            "--jsonrpc-envelopes",
+            "--client",
+            "--mock-provider",
+            "--record-replay",
+        ],
+    );
+}
+
+#[test]
+fn generated_code_compiles_with_version_negotiation() {
+    assert_compiles(
+        "0.3.0-version-negotiation",
+        &[
+            "--spec",
+            "0.3.0",
+            "--response-types",
+            "--jsonrpc-envelopes",
+            "--client",
+            "--version-negotiation",
+        ],
+    );
+    assert_compiles(
+        "all-specs-version-negotiation",
+        &[
+            "--all-specs",
+            "--response-types",
+            "--jsonrpc-envelopes",
+            "--client",
+            "--mock-provider",
+            "--record-replay",
+            "--version-negotiation",
+        ],
+    );
+}
+
+#[test]
+fn generated_requests_follow_param_structure() {
+    assert_stub_tests_pass("requests", &["--spec", "0.3.0"]);
+}
+
+#[test]
+fn generated_requests_accept_both_param_forms() {
+    assert_stub_tests_pass(
+        "params",
+        &["--spec", "0.3.0", "--spec-file", SYNTHETIC_SPEC],
+    );
+}
+
+#[test]
+fn generated_adapters_wrap_nested_serde_with_fields() {
+    assert_stub_tests_pass(
+        "adapters",
+        &["--spec", "0.3.0", "--spec-file", SYNTHETIC_SPEC],
+    );
+}
+
+#[test]
+fn generated_ref_types_nest_through_options() {
+    assert_stub_tests_pass(
+        "ref_types",
+        &[
+            "--spec",
+            "0.3.0",
+            "--spec-file",
+            SYNTHETIC_SPEC,
+            "--ref-type",
+            "LabeledPair",
+            "--ref-type",
+            "LabeledValue",
+        ],
+    );
+}
+
+#[test]
+fn generated_borrowed_types_borrow_from_input() {
+    assert_stub_tests_pass(
+        "borrowed",
+        &[
+            "--spec",
+            "0.3.0",
+            "--spec-file",
+            SYNTHETIC_SPEC,
+            "--borrowed-type",
+            "LabeledValue",
+        ],
+    );
+}
+
+#[test]
+fn generated_borrowed_enums_borrow_from_input() {
+    assert_stub_tests_pass(
+        "borrowed_blocks",
+        &[
+            "--spec",
+            "0.3.0",
+            "--oneof-enums",
+            "--borrowed-type",
+            "BlockWithTxs",
+        ],
+    );
+}
+
+#[test]
+fn generated_envelopes_round_trip() {
+    assert_stub_tests_pass(
+        "envelopes",
+        &["--spec", "0.3.0", "--response-types", "--jsonrpc-envelopes"],
+    );
+}
+
+#[test]
+fn generated_client_works_over_in_memory_transport() {
+    assert_stub_tests_pass(
+        "client",
+        &[
+            "--spec",
+            "0.3.0",
+            "--response-types",
+            "--jsonrpc-envelopes",
+            "--client",
+        ],
+    );
+}
+
+#[test]
+fn generated_mock_provider_answers_client() {
+    assert_stub_tests_pass(
+        "mock_provider",
+        &[
+            "--spec",
+            "0.3.0",
+            "--response-types",
+            "--jsonrpc-envelopes",
+            "--client",
+            "--mock-provider",
+        ],
+    );
+}
+
+#[test]
+fn generated_record_replay_transports_replay_fixtures() {
+    assert_stub_tests_pass(
+        "record_replay",
+        &[
+            "--spec",
+            "0.3.0",
+            "--response-types",
+            "--jsonrpc-envelopes",
+            "--client",
+            "--record-replay",
+        ],
+    );
+}
+
+#[test]
+fn generated_version_conversions_convert_values() {
+    assert_stub_tests_pass(
+        "version_conversions",
+        &[
+            "--spec",
+            "0.2.1,0.3.0",
+            "--response-types",
+            "--version-conversions",
+        ],
+    );
+}
+
+#[test]
+fn generated_version_negotiation_detects_versions() {
+    assert_stub_tests_pass(
+        "negotiation",
+        &[
+            "--spec",
+            "0.1.0,0.2.1,0.3.0",
+            "--response-types",
+            "--jsonrpc-envelopes",
+            "--client",
+            "--version-negotiation",
+        ],
+    );
+}
@@ -0,0 +1,80 @@
+//! Checks that generated code is left unchanged by rustfmt.
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+mod common;
+
+use common::{generate, SPEC_VERSIONS};
+
+fn assert_rustfmt_clean(args: &[&str]) {
+    let generated = generate(args);
+
+    // `rustfmt --check` exits successfully on code read from stdin, so the formatted code is
+    // compared instead
+    let mut rustfmt =
+        Command::new(std::env::var("RUSTFMT").unwrap_or_else(|_| String::from("rustfmt")))
+            .args(["--edition", "2021", "--emit", "stdout"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("failed to run rustfmt");
+    rustfmt
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(generated.as_bytes())
+        .unwrap();
+    let output = rustfmt.wait_with_output().unwrap();
+
+    assert!(
+        output.status.success(),
+        "rustfmt failed on code generated with args {:?}:\n{}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let formatted = String::from_utf8(output.stdout).expect("formatted code is not valid UTF-8");
+    if formatted != generated {
+        let first_diff = generated
+            .lines()
+            .zip(formatted.lines())
+            .position(|(generated, formatted)| generated != formatted)
+            .unwrap_or_else(|| generated.lines().count().min(formatted.lines().count()));
+
+        panic!(
+            "rustfmt changes code generated with args {:?} (first difference at line {}):\n\
+            generated: {:?}\n\
+            formatted: {:?}",
+            args,
+            first_diff + 1,
+            generated.lines().nth(first_diff),
+            formatted.lines().nth(first_diff),
+        );
+    }
+}
+
+#[test]
+fn generated_code_is_rustfmt_clean() {
+    for version in SPEC_VERSIONS {
+        assert_rustfmt_clean(&["--spec", version]);
+    }
+}
+
+#[test]
+fn generated_client_is_rustfmt_clean() {
+    for version in SPEC_VERSIONS {
+        assert_rustfmt_clean(&[
+            "--spec",
+            version,
+            "--response-types",
+            "--jsonrpc-envelopes",
+            "--client",
+            "--mock-provider",
+            "--record-replay",
+        ]);
+    }
+}
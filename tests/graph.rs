@@ -0,0 +1,57 @@
+//! Checks the schema reference graph exported by the `graph` subcommand.
+
+mod common;
+
+use common::{generate, SPEC_VERSIONS};
+
+#[test]
+fn dependents_include_methods() {
+    let graph: serde_json::Value = serde_json::from_str(&generate(&[
+        "--spec",
+        "0.3.0",
+        "graph",
+        "--format",
+        "json",
+        "--dependents-of",
+        "BLOCK_HEADER",
+    ]))
+    .unwrap();
+
+    let nodes = graph["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert!(nodes.contains(&"BLOCK_WITH_TXS"));
+    assert!(nodes.contains(&"starknet_getBlockWithTxs"));
+    assert!(!nodes.contains(&"starknet_getNonce"));
+
+    assert!(graph["edges"].as_array().unwrap().iter().any(|edge| {
+        edge["from"] == "BLOCK_WITH_TXS"
+            && edge["to"] == "BLOCK_HEADER"
+            && edge["kind"] == "flatten"
+    }));
+    assert!(graph["edges"].as_array().unwrap().iter().any(|edge| {
+        edge["from"] == "starknet_getBlockWithTxs"
+            && edge["to"] == "BLOCK_WITH_TXS"
+            && edge["kind"] == "result"
+    }));
+}
+
+#[test]
+fn graph_formats() {
+    for version in SPEC_VERSIONS {
+        let dot = generate(&["--spec", version, "graph"]);
+        assert!(dot.starts_with("digraph schemas {"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    let dot = generate(&["--spec", "0.2.1", "graph"]);
+    assert!(dot.starts_with("digraph schemas {"));
+    assert!(dot.contains("\"starknet_getNonce\" -> \"CONTRACT_NOT_FOUND\" [label=\"error\"];"));
+
+    let mermaid = generate(&["--spec", "0.2.1", "graph", "--format", "mermaid"]);
+    assert!(mermaid.starts_with("graph LR"));
+    assert!(mermaid.contains("    TXN -->|oneOf| INVOKE_TXN"));
+}
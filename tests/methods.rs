@@ -0,0 +1,42 @@
+//! Checks that filtering methods with `--methods` only removes types from the generated code.
+
+mod common;
+
+use common::{generate, run, SPEC_VERSIONS};
+
+const METHODS: &str = "starknet_call,starknet_getEvents,starknet_getNonce";
+
+#[test]
+fn filtered_types_are_unchanged() {
+    for version in SPEC_VERSIONS {
+        let full = generate(&["--spec", version]);
+        let filtered = generate(&["--spec", version, "--methods", METHODS]);
+
+        assert!(filtered.contains("pub struct GetNonceRequest {"));
+        assert!(filtered.contains("pub struct EmittedEvent {"));
+        assert!(!filtered.contains("pub struct BlockHeader {"));
+        assert!(!filtered.contains("pub struct GetBlockWithTxsRequest {"));
+
+        // Error codes only keep the errors of selected methods, everything else must be
+        // generated exactly the same way
+        for block in filtered.split("\n\n").filter(|block| {
+            block.starts_with("#[") || block.starts_with("///") || block.starts_with("impl")
+        }) {
+            if block.contains("pub enum StarknetError") {
+                continue;
+            }
+
+            assert!(
+                full.contains(block),
+                "block not found in unfiltered output for {version}:\n{block}"
+            );
+        }
+    }
+}
+
+#[test]
+fn unknown_methods_are_rejected() {
+    let output = run(&["--spec", "0.3.0", "--methods", "starknet_unknown"]);
+
+    assert!(!output.status.success());
+}
@@ -0,0 +1,32 @@
+//! Checks the reports of decisions made from the spec, which are only printed on request.
+
+mod common;
+
+/// Runs the codegen binary with `args` and returns what it printed to `stderr`.
+fn report(args: &[&str]) -> String {
+    let output = common::run(args);
+
+    assert!(output.status.success(), "codegen failed with args {args:?}");
+
+    output.stderr
+}
+
+#[test]
+fn fixed_fields_are_reported_on_request() {
+    let stderr = report(&["--spec", "0.3.0", "--report-fixed-fields"]);
+
+    assert!(stderr.contains("Inferred fixed field `InvokeTransactionReceipt.type`: \"INVOKE\"\n"));
+    assert!(!stderr.contains("`InvokeTransactionV1.version`"));
+
+    assert!(!report(&["--spec", "0.3.0"]).contains("fixed field"));
+}
+
+#[test]
+fn flatten_decisions_are_reported_on_request() {
+    let stderr = report(&["--spec", "0.3.0", "--report-flatten"]);
+
+    assert!(stderr.contains("Flattening `BLOCK_HEADER`: only used as `allOf` fragment\n"));
+    assert!(stderr.contains("Not flattening `EVENT_FILTER`: profile override\n"));
+
+    assert!(!report(&["--spec", "0.3.0"]).contains("Flattening"));
+}
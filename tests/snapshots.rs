@@ -0,0 +1,57 @@
+//! Compares generated code against the golden files in `tests/snapshots`.
+//!
+//! Run with `BLESS=1` to overwrite the golden files with the current output after an intended
+//! change in generated code.
+
+use std::path::PathBuf;
+
+mod common;
+
+use common::{generate, SPEC_VERSIONS};
+
+fn assert_snapshot(name: &str, args: &[&str]) {
+    let actual = generate(args);
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.rs"));
+
+    if std::env::var("BLESS").as_deref() == Ok("1") {
+        std::fs::write(&path, &actual).expect("failed to write snapshot");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "failed to read snapshot {}: {err}. Run with `BLESS=1` to create it.",
+            path.display()
+        )
+    });
+
+    if expected != actual {
+        let first_diff = expected
+            .lines()
+            .zip(actual.lines())
+            .position(|(expected, actual)| expected != actual)
+            .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+
+        panic!(
+            "generated code does not match snapshot {} (first difference at line {}):\n\
+            expected: {:?}\n\
+            actual:   {:?}\n\
+            Run with `BLESS=1` to update the snapshot if the change is intended.",
+            path.display(),
+            first_diff + 1,
+            expected.lines().nth(first_diff),
+            actual.lines().nth(first_diff),
+        );
+    }
+}
+
+#[test]
+fn snapshot_all_spec_versions() {
+    for version in SPEC_VERSIONS {
+        assert_snapshot(version, &["--spec", version]);
+    }
+}
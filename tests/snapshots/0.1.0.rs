@@ -0,0 +1,3096 @@
+// AUTO-GENERATED CODE. DO NOT EDIT
+// To change the code generated, modify the codegen tool instead:
+//     https://github.com/xJonathanLEI/starknet-jsonrpc-codegen
+
+// Code generated with version:
+//     <commit>
+
+// Code generation requested but not implemented for these types:
+// - `BLOCK_ID`
+// - `PENDING_TXN_RECEIPT`
+// - `TXN`
+// - `TXN_RECEIPT`
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::serde_as;
+use starknet_core::{
+    serde::{byte_array::base64, unsigned_field_element::UfeHex},
+    types::FieldElement,
+};
+
+pub use starknet_core::types::L1Address as EthAddress;
+
+use super::{serde_impls::NumAsHex, *};
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct BlockHeader {
+    #[serde_as(as = "UfeHex")]
+    pub block_hash: FieldElement,
+    /// The hash of this block's parent
+    #[serde_as(as = "UfeHex")]
+    pub parent_hash: FieldElement,
+    /// The block number (its height)
+    pub block_number: u64,
+    /// The new global state root
+    #[serde_as(as = "UfeHex")]
+    pub new_root: FieldElement,
+    /// The time in which the block was created, encoded in Unix time
+    pub timestamp: u64,
+    /// The Starknet identity of the sequencer submitting this block
+    #[serde_as(as = "UfeHex")]
+    pub sequencer_address: FieldElement,
+}
+
+/// The status of the block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "ACCEPTED_ON_L2")]
+    AcceptedOnL2,
+    #[serde(rename = "ACCEPTED_ON_L1")]
+    AcceptedOnL1,
+    #[serde(rename = "REJECTED")]
+    Rejected,
+}
+
+/// A tag specifying a dynamic reference to a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockTag {
+    #[serde(rename = "latest")]
+    Latest,
+    #[serde(rename = "pending")]
+    Pending,
+}
+
+/// The block object.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct BlockWithTxHashes {
+    pub status: BlockStatus,
+    #[serde(flatten)]
+    pub block_header: BlockHeader,
+    /// The hashes of the transactions included in this block
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub transactions: Vec<FieldElement>,
+}
+
+/// The block object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct BlockWithTxs {
+    pub status: BlockStatus,
+    #[serde(flatten)]
+    pub block_header: BlockHeader,
+    /// The transactions in this block
+    pub transactions: Vec<Transaction>,
+}
+
+/// The definition of a Starknet contract class.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct ContractClass {
+    /// A base64 representation of the compressed program code
+    #[serde(with = "base64")]
+    pub program: Vec<u8>,
+    pub entry_points_by_type: EntryPointsByType,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct ContractEntryPoint {
+    /// The offset of the entry point in the program
+    #[serde_as(as = "NumAsHex")]
+    pub offset: u64,
+    /// A unique identifier of the entry point (function) in the program
+    #[serde_as(as = "UfeHex")]
+    pub selector: FieldElement,
+}
+
+/// Declare contract transaction.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct DeclareTransaction {
+    #[serde(flatten)]
+    pub common_txn_properties: TransactionMeta,
+    /// The hash of the declared class
+    #[serde_as(as = "UfeHex")]
+    pub class_hash: FieldElement,
+    /// The address of the account contract sending the declaration transaction
+    #[serde_as(as = "UfeHex")]
+    pub sender_address: FieldElement,
+}
+
+/// Declare transaction receipt.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct DeclareTransactionReceipt {
+    /// The hash identifying the transaction
+    #[serde_as(as = "UfeHex")]
+    pub transaction_hash: FieldElement,
+    /// The fee that was charged by the sequencer
+    #[serde_as(as = "UfeHex")]
+    pub actual_fee: FieldElement,
+    pub status: TransactionStatus,
+    /// Extra information pertaining to the status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_data: Option<String>,
+    #[serde_as(as = "UfeHex")]
+    pub block_hash: FieldElement,
+    pub block_number: u64,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct DeclaredContractItem {
+    /// The hash of the contract code
+    #[serde_as(as = "UfeHex")]
+    pub class_hash: FieldElement,
+}
+
+/// Deploy contract transaction.
+///
+/// The structure of a deploy transaction. Note that this transaction type is deprecated and will no
+/// longer be supported in future versions.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct DeployTransaction {
+    /// The hash identifying the transaction
+    #[serde_as(as = "UfeHex")]
+    pub transaction_hash: FieldElement,
+    /// The hash of the deployed contract's class
+    #[serde_as(as = "UfeHex")]
+    pub class_hash: FieldElement,
+    /// Version of the transaction scheme
+    #[serde_as(as = "NumAsHex")]
+    pub version: u64,
+    pub r#type: String,
+    /// The address of the deployed contract
+    #[serde_as(as = "UfeHex")]
+    pub contract_address: FieldElement,
+    /// The salt for the address of the deployed contract
+    #[serde_as(as = "UfeHex")]
+    pub contract_address_salt: FieldElement,
+    /// The parameters passed to the constructor
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub constructor_calldata: Vec<FieldElement>,
+}
+
+/// Deploy transaction receipt.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct DeployTransactionReceipt {
+    /// The hash identifying the transaction
+    #[serde_as(as = "UfeHex")]
+    pub transaction_hash: FieldElement,
+    /// The fee that was charged by the sequencer
+    #[serde_as(as = "UfeHex")]
+    pub actual_fee: FieldElement,
+    pub status: TransactionStatus,
+    /// Extra information pertaining to the status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_data: Option<String>,
+    #[serde_as(as = "UfeHex")]
+    pub block_hash: FieldElement,
+    pub block_number: u64,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct DeployedContractItem {
+    /// The address of the contract
+    #[serde_as(as = "UfeHex")]
+    pub address: FieldElement,
+    /// The hash of the contract code
+    #[serde_as(as = "UfeHex")]
+    pub class_hash: FieldElement,
+}
+
+/// An event emitted as a result of transaction execution.
+///
+/// Event information decorated with metadata on where it was emitted.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct EmittedEvent {
+    #[serde(flatten)]
+    pub event: Event,
+    /// The hash of the block in which the event was emitted
+    #[serde_as(as = "UfeHex")]
+    pub block_hash: FieldElement,
+    /// The number of the block in which the event was emitted
+    pub block_number: u64,
+    /// The transaction that emitted the event
+    #[serde_as(as = "UfeHex")]
+    pub transaction_hash: FieldElement,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct EntryPointsByType {
+    #[serde(rename = "CONSTRUCTOR")]
+    pub constructor: Vec<ContractEntryPoint>,
+    #[serde(rename = "EXTERNAL")]
+    pub external: Vec<ContractEntryPoint>,
+    #[serde(rename = "L1_HANDLER")]
+    pub l1_handler: Vec<ContractEntryPoint>,
+}
+
+/// A Starknet event.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct Event {
+    #[serde_as(as = "UfeHex")]
+    pub from_address: FieldElement,
+    #[serde(flatten)]
+    pub event_content: EventContent,
+}
+
+/// Event content.
+///
+/// The content of an event.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct EventContent {
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub keys: Vec<FieldElement>,
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub data: Vec<FieldElement>,
+}
+
+/// An event filter/query.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct EventFilter {
+    /// From block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<BlockId>,
+    /// To block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_block: Option<BlockId>,
+    /// From contract
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<UfeHex>")]
+    pub address: Option<FieldElement>,
+    /// The values used to filter the events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<Vec<UfeHex>>")]
+    pub keys: Option<Vec<FieldElement>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct EventFilterWithPage {
+    #[serde(flatten)]
+    pub event_filter: EventFilter,
+    #[serde(flatten)]
+    pub result_page_request: ResultPageRequest,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct FeeEstimate {
+    /// The Ethereum gas cost of the transaction (see
+    /// https://docs.starknet.io/docs/fees/fee-mechanism for more info)
+    #[serde_as(as = "NumAsHex")]
+    pub gas_consumed: u64,
+    /// The gas price (in gwei) that was used in the cost estimation
+    #[serde_as(as = "NumAsHex")]
+    pub gas_price: u64,
+    /// The estimated fee for the transaction (in gwei), product of gas_consumed and gas_price
+    #[serde_as(as = "NumAsHex")]
+    pub overall_fee: u64,
+}
+
+/// Function call information.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct FunctionCall {
+    #[serde_as(as = "UfeHex")]
+    pub contract_address: FieldElement,
+    #[serde_as(as = "UfeHex")]
+    pub entry_point_selector: FieldElement,
+    /// The parameters passed to the function
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub calldata: Vec<FieldElement>,
+}
+
+/// Invoke contract transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct InvokeTransaction {
+    #[serde(flatten)]
+    pub common_txn_properties: TransactionMeta,
+    /// The function the transaction invokes
+    #[serde(flatten)]
+    pub function_call: FunctionCall,
+}
+
+/// Invoke transaction receipt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct InvokeTransactionReceipt {
+    #[serde(flatten)]
+    pub common_receipt_properties: TransactionReceiptMeta,
+    #[serde(flatten)]
+    pub invoke_txn_receipt_properties: InvokeTransactionReceiptData,
+}
+
+/// Properties specific to invoke transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct InvokeTransactionReceiptData {
+    pub messages_sent: Vec<MsgToL1>,
+    /// In case this transaction was an L1 handler, this is the original message that invoked it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l1_origin_message: Option<MsgToL2>,
+    /// The events emitted as part of this transaction
+    pub events: Vec<Event>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct MsgToL1 {
+    /// The target L1 address the message is sent to
+    #[serde_as(as = "UfeHex")]
+    pub to_address: FieldElement,
+    /// The payload of the message
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub payload: Vec<FieldElement>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct MsgToL2 {
+    /// The originating L1 contract that sent the message
+    pub from_address: EthAddress,
+    /// The payload of the meesage. The call data to the L1 handler
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub payload: Vec<FieldElement>,
+}
+
+/// The updated nonce per contract address.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct NonceUpdate {
+    /// The address of the contract
+    #[serde_as(as = "UfeHex")]
+    pub contract_address: FieldElement,
+    /// The nonce for the given address at the end of the block
+    #[serde_as(as = "UfeHex")]
+    pub nonce: FieldElement,
+}
+
+/// The dynamic block being constructed by the sequencer. Note that this object will be deprecated
+/// upon decentralization.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct PendingBlockWithTxHashes {
+    /// The hashes of the transactions included in this block
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub transactions: Vec<FieldElement>,
+    /// The time in which the block was created, encoded in Unix time
+    pub timestamp: u64,
+    /// The Starknet identity of the sequencer submitting this block
+    #[serde_as(as = "UfeHex")]
+    pub sequencer_address: FieldElement,
+    /// The hash of this block's parent
+    #[serde_as(as = "UfeHex")]
+    pub parent_hash: FieldElement,
+}
+
+/// The dynamic block being constructed by the sequencer. Note that this object will be deprecated
+/// upon decentralization.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct PendingBlockWithTxs {
+    /// The transactions in this block
+    pub transactions: Vec<Transaction>,
+    /// The time in which the block was created, encoded in Unix time
+    pub timestamp: u64,
+    /// The Starknet identity of the sequencer submitting this block
+    #[serde_as(as = "UfeHex")]
+    pub sequencer_address: FieldElement,
+    /// The hash of this block's parent
+    #[serde_as(as = "UfeHex")]
+    pub parent_hash: FieldElement,
+}
+
+/// Pending invoke transaction receipt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct PendingInvokeTransactionReceipt {
+    #[serde(flatten)]
+    pub pending_common_receipt_properties: PendingTransactionReceiptMeta,
+    #[serde(flatten)]
+    pub invoke_txn_receipt_properties: InvokeTransactionReceiptData,
+}
+
+/// Common properties for a pending transaction receipt.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct PendingTransactionReceiptMeta {
+    /// The hash identifying the transaction
+    #[serde_as(as = "UfeHex")]
+    pub transaction_hash: FieldElement,
+    /// The fee that was charged by the sequencer
+    #[serde_as(as = "UfeHex")]
+    pub actual_fee: FieldElement,
+}
+
+/// A request for a specific page of results.
+///
+/// A specification of a result page to retrieve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct ResultPageRequest {
+    pub page_size: u64,
+    pub page_number: u64,
+}
+
+/// JSON-RPC error codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+pub enum StarknetError {
+    /// Failed to write transaction
+    #[error("Failed to write transaction")]
+    FailedToReceiveTransaction,
+    /// Contract not found
+    #[error("Contract not found")]
+    ContractNotFound,
+    /// Invalid message selector
+    #[error("Invalid message selector")]
+    InvalidMessageSelector,
+    /// Invalid call data
+    #[error("Invalid call data")]
+    InvalidCallData,
+    /// Invalid block id
+    #[error("Invalid block id")]
+    InvalidBlockId,
+    /// Invalid transaction hash
+    #[error("Invalid transaction hash")]
+    InvalidTransactionHash,
+    /// Invalid transaction index in a block
+    #[error("Invalid transaction index in a block")]
+    InvalidTransactionIndex,
+    /// The supplied contract class hash is invalid or unknown
+    #[error("The supplied contract class hash is invalid or unknown")]
+    InvalidContractClassHash,
+    /// Requested page size is too big
+    #[error("Requested page size is too big")]
+    PageSizeTooBig,
+    /// There are no blocks
+    #[error("There are no blocks")]
+    NoBlocks,
+    /// Contract error
+    #[error("Contract error")]
+    ContractError,
+    /// Invalid contract class
+    #[error("Invalid contract class")]
+    InvalidContractClass,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct StateDiff {
+    pub storage_diffs: Vec<StorageDiffItem>,
+    pub declared_contracts: Vec<DeclaredContractItem>,
+    pub deployed_contracts: Vec<DeployedContractItem>,
+    pub nonces: Vec<NonceUpdate>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct StateUpdate {
+    #[serde_as(as = "UfeHex")]
+    pub block_hash: FieldElement,
+    /// The new global state root
+    #[serde_as(as = "UfeHex")]
+    pub new_root: FieldElement,
+    /// The previous global state root
+    #[serde_as(as = "UfeHex")]
+    pub old_root: FieldElement,
+    /// The change in state applied in this block, given as a mapping of addresses to the new values
+    /// and/or new contracts
+    pub state_diff: StateDiff,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct StorageDiffItem {
+    /// The contract address for which the state changed
+    #[serde_as(as = "UfeHex")]
+    pub address: FieldElement,
+    /// The key of the changed value
+    #[serde_as(as = "UfeHex")]
+    pub key: FieldElement,
+    /// The new value applied to the given address
+    #[serde_as(as = "UfeHex")]
+    pub value: FieldElement,
+}
+
+/// An object describing the node synchronization status.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct SyncStatus {
+    /// The hash of the block from which the sync started
+    #[serde_as(as = "UfeHex")]
+    pub starting_block_hash: FieldElement,
+    /// The number (height) of the block from which the sync started
+    #[serde_as(as = "NumAsHex")]
+    pub starting_block_num: u64,
+    /// The hash of the current block being synchronized
+    #[serde_as(as = "UfeHex")]
+    pub current_block_hash: FieldElement,
+    /// The number (height) of the current block being synchronized
+    #[serde_as(as = "NumAsHex")]
+    pub current_block_num: u64,
+    /// The hash of the estimated highest block to be synchronized
+    #[serde_as(as = "UfeHex")]
+    pub highest_block_hash: FieldElement,
+    /// The number (height) of the estimated highest block to be synchronized
+    #[serde_as(as = "NumAsHex")]
+    pub highest_block_num: u64,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct TransactionMeta {
+    /// The hash identifying the transaction
+    #[serde_as(as = "UfeHex")]
+    pub transaction_hash: FieldElement,
+    /// The maximal fee that can be charged for including the transaction
+    #[serde_as(as = "UfeHex")]
+    pub max_fee: FieldElement,
+    /// Version of the transaction scheme
+    #[serde_as(as = "NumAsHex")]
+    pub version: u64,
+    #[serde_as(as = "Vec<UfeHex>")]
+    pub signature: Vec<FieldElement>,
+    #[serde_as(as = "UfeHex")]
+    pub nonce: FieldElement,
+    pub r#type: String,
+}
+
+/// Common properties for a transaction receipt.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "no_unknown_fields", serde(deny_unknown_fields))]
+pub struct TransactionReceiptMeta {
+    /// The hash identifying the transaction
+    #[serde_as(as = "UfeHex")]
+    pub transaction_hash: FieldElement,
+    /// The fee that was charged by the sequencer
+    #[serde_as(as = "UfeHex")]
+    pub actual_fee: FieldElement,
+    pub status: TransactionStatus,
+    /// Extra information pertaining to the status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_data: Option<String>,
+    #[serde_as(as = "UfeHex")]
+    pub block_hash: FieldElement,
+    pub block_number: u64,
+}
+
+/// The status of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "ACCEPTED_ON_L2")]
+    AcceptedOnL2,
+    #[serde(rename = "ACCEPTED_ON_L1")]
+    AcceptedOnL1,
+    #[serde(rename = "REJECTED")]
+    Rejected,
+}
+
+/// Request for method starknet_addDeclareTransaction
+#[derive(Debug, Clone)]
+pub struct AddDeclareTransactionRequest {
+    /// The class to be declared
+    pub contract_class: ContractClass,
+    /// Version of the transaction scheme
+    pub version: u64,
+}
+
+/// Reference version of [AddDeclareTransactionRequest].
+#[derive(Debug, Clone)]
+pub struct AddDeclareTransactionRequestRef<'a> {
+    pub contract_class: &'a ContractClass,
+    pub version: &'a u64,
+}
+
+/// Request for method starknet_addDeployTransaction
+#[derive(Debug, Clone)]
+pub struct AddDeployTransactionRequest {
+    /// The salt used for calculting the contract address
+    pub contract_address_salt: FieldElement,
+    /// The calldata passed to the contract's constructor function
+    pub constructor_calldata: Vec<FieldElement>,
+    /// The definition of the deployed contract
+    pub contract_definition: ContractClass,
+}
+
+/// Reference version of [AddDeployTransactionRequest].
+#[derive(Debug, Clone)]
+pub struct AddDeployTransactionRequestRef<'a> {
+    pub contract_address_salt: &'a FieldElement,
+    pub constructor_calldata: &'a [FieldElement],
+    pub contract_definition: &'a ContractClass,
+}
+
+/// Request for method starknet_addInvokeTransaction
+#[derive(Debug, Clone)]
+pub struct AddInvokeTransactionRequest {
+    /// The information needed to invoke the function (contract, selector, call data)
+    pub function_invocation: FunctionCall,
+    /// The signature over the transaction
+    pub signature: Vec<FieldElement>,
+    /// The maximum fee paid for the transaction, in ETH
+    pub max_fee: u64,
+    /// Version of the transaction scheme
+    pub version: u64,
+}
+
+/// Reference version of [AddInvokeTransactionRequest].
+#[derive(Debug, Clone)]
+pub struct AddInvokeTransactionRequestRef<'a> {
+    pub function_invocation: &'a FunctionCall,
+    pub signature: &'a [FieldElement],
+    pub max_fee: &'a u64,
+    pub version: &'a u64,
+}
+
+/// Request for method starknet_blockHashAndNumber
+#[derive(Debug, Clone)]
+pub struct BlockHashAndNumberRequest;
+
+/// Request for method starknet_blockNumber
+#[derive(Debug, Clone)]
+pub struct BlockNumberRequest;
+
+/// Request for method starknet_call
+#[derive(Debug, Clone)]
+pub struct CallRequest {
+    pub request: FunctionCall,
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag,
+    /// for the block referencing the state or call the transaction on.
+    pub block_id: BlockId,
+}
+
+/// Reference version of [CallRequest].
+#[derive(Debug, Clone)]
+pub struct CallRequestRef<'a> {
+    pub request: &'a FunctionCall,
+    pub block_id: &'a BlockId,
+}
+
+/// Request for method starknet_chainId
+#[derive(Debug, Clone)]
+pub struct ChainIdRequest;
+
+/// Request for method starknet_estimateFee
+#[derive(Debug, Clone)]
+pub struct EstimateFeeRequest {
+    pub request: InvokeTransaction,
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag,
+    /// for the block referencing the state or call the transaction on.
+    pub block_id: BlockId,
+}
+
+/// Reference version of [EstimateFeeRequest].
+#[derive(Debug, Clone)]
+pub struct EstimateFeeRequestRef<'a> {
+    pub request: &'a InvokeTransaction,
+    pub block_id: &'a BlockId,
+}
+
+/// Request for method starknet_getBlockTransactionCount
+#[derive(Debug, Clone)]
+pub struct GetBlockTransactionCountRequest {
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag
+    pub block_id: BlockId,
+}
+
+/// Reference version of [GetBlockTransactionCountRequest].
+#[derive(Debug, Clone)]
+pub struct GetBlockTransactionCountRequestRef<'a> {
+    pub block_id: &'a BlockId,
+}
+
+/// Request for method starknet_getBlockWithTxHashes
+#[derive(Debug, Clone)]
+pub struct GetBlockWithTxHashesRequest {
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag
+    pub block_id: BlockId,
+}
+
+/// Reference version of [GetBlockWithTxHashesRequest].
+#[derive(Debug, Clone)]
+pub struct GetBlockWithTxHashesRequestRef<'a> {
+    pub block_id: &'a BlockId,
+}
+
+/// Request for method starknet_getBlockWithTxs
+#[derive(Debug, Clone)]
+pub struct GetBlockWithTxsRequest {
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag
+    pub block_id: BlockId,
+}
+
+/// Reference version of [GetBlockWithTxsRequest].
+#[derive(Debug, Clone)]
+pub struct GetBlockWithTxsRequestRef<'a> {
+    pub block_id: &'a BlockId,
+}
+
+/// Request for method starknet_getClassAt
+#[derive(Debug, Clone)]
+pub struct GetClassAtRequest {
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag
+    pub block_id: BlockId,
+    /// The address of the contract whose class definition will be returned
+    pub contract_address: FieldElement,
+}
+
+/// Reference version of [GetClassAtRequest].
+#[derive(Debug, Clone)]
+pub struct GetClassAtRequestRef<'a> {
+    pub block_id: &'a BlockId,
+    pub contract_address: &'a FieldElement,
+}
+
+/// Request for method starknet_getClassHashAt
+#[derive(Debug, Clone)]
+pub struct GetClassHashAtRequest {
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag
+    pub block_id: BlockId,
+    /// The address of the contract whose class hash will be returned
+    pub contract_address: FieldElement,
+}
+
+/// Reference version of [GetClassHashAtRequest].
+#[derive(Debug, Clone)]
+pub struct GetClassHashAtRequestRef<'a> {
+    pub block_id: &'a BlockId,
+    pub contract_address: &'a FieldElement,
+}
+
+/// Request for method starknet_getClass
+#[derive(Debug, Clone)]
+pub struct GetClassRequest {
+    /// The hash of the requested contract class
+    pub class_hash: FieldElement,
+}
+
+/// Reference version of [GetClassRequest].
+#[derive(Debug, Clone)]
+pub struct GetClassRequestRef<'a> {
+    pub class_hash: &'a FieldElement,
+}
+
+/// Request for method starknet_getEvents
+#[derive(Debug, Clone)]
+pub struct GetEventsRequest {
+    pub filter: EventFilterWithPage,
+}
+
+/// Reference version of [GetEventsRequest].
+#[derive(Debug, Clone)]
+pub struct GetEventsRequestRef<'a> {
+    pub filter: &'a EventFilterWithPage,
+}
+
+/// Request for method starknet_getNonce
+#[derive(Debug, Clone)]
+pub struct GetNonceRequest {
+    /// The address of the contract whose nonce we're seeking
+    pub contract_address: FieldElement,
+}
+
+/// Reference version of [GetNonceRequest].
+#[derive(Debug, Clone)]
+pub struct GetNonceRequestRef<'a> {
+    pub contract_address: &'a FieldElement,
+}
+
+/// Request for method starknet_getStateUpdate
+#[derive(Debug, Clone)]
+pub struct GetStateUpdateRequest {
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag
+    pub block_id: BlockId,
+}
+
+/// Reference version of [GetStateUpdateRequest].
+#[derive(Debug, Clone)]
+pub struct GetStateUpdateRequestRef<'a> {
+    pub block_id: &'a BlockId,
+}
+
+/// Request for method starknet_getStorageAt
+#[derive(Debug, Clone)]
+pub struct GetStorageAtRequest {
+    /// The address of the contract to read from
+    pub contract_address: FieldElement,
+    /// The key to the storage value for the given contract
+    pub key: FieldElement,
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag
+    pub block_id: BlockId,
+}
+
+/// Reference version of [GetStorageAtRequest].
+#[derive(Debug, Clone)]
+pub struct GetStorageAtRequestRef<'a> {
+    pub contract_address: &'a FieldElement,
+    pub key: &'a FieldElement,
+    pub block_id: &'a BlockId,
+}
+
+/// Request for method starknet_getTransactionByBlockIdAndIndex
+#[derive(Debug, Clone)]
+pub struct GetTransactionByBlockIdAndIndexRequest {
+    /// The hash of the requested block, or number (height) of the requested block, or a block tag
+    pub block_id: BlockId,
+    pub index: u64,
+}
+
+/// Reference version of [GetTransactionByBlockIdAndIndexRequest].
+#[derive(Debug, Clone)]
+pub struct GetTransactionByBlockIdAndIndexRequestRef<'a> {
+    pub block_id: &'a BlockId,
+    pub index: &'a u64,
+}
+
+/// Request for method starknet_getTransactionByHash
+#[derive(Debug, Clone)]
+pub struct GetTransactionByHashRequest {
+    pub transaction_hash: FieldElement,
+}
+
+/// Reference version of [GetTransactionByHashRequest].
+#[derive(Debug, Clone)]
+pub struct GetTransactionByHashRequestRef<'a> {
+    pub transaction_hash: &'a FieldElement,
+}
+
+/// Request for method starknet_getTransactionReceipt
+#[derive(Debug, Clone)]
+pub struct GetTransactionReceiptRequest {
+    pub transaction_hash: FieldElement,
+}
+
+/// Reference version of [GetTransactionReceiptRequest].
+#[derive(Debug, Clone)]
+pub struct GetTransactionReceiptRequestRef<'a> {
+    pub transaction_hash: &'a FieldElement,
+}
+
+/// Request for method starknet_pendingTransactions
+#[derive(Debug, Clone)]
+pub struct PendingTransactionsRequest;
+
+/// Request for method starknet_protocolVersion
+#[derive(Debug, Clone)]
+pub struct ProtocolVersionRequest;
+
+/// Request for method starknet_syncing
+#[derive(Debug, Clone)]
+pub struct SyncingRequest;
+
+impl Serialize for AddDeclareTransactionRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub contract_class: &'a ContractClass,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub version: &'a u64,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            contract_class: &self.contract_class,
+        })?;
+        seq.serialize_element(&Field1 {
+            version: &self.version,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for AddDeclareTransactionRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub contract_class: &'a ContractClass,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub version: &'a u64,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            contract_class: self.contract_class,
+        })?;
+        seq.serialize_element(&Field1 {
+            version: self.version,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for AddDeclareTransactionRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub contract_class: ContractClass,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub version: u64,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub contract_class: ContractClass,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub version: u64,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 2 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                contract_class: field0.contract_class,
+                version: field1.version,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                contract_class: object.contract_class,
+                version: object.version,
+            })
+        }
+    }
+}
+
+impl Serialize for AddDeployTransactionRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "UfeHex")]
+            pub contract_address_salt: &'a FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "[UfeHex]")]
+            pub constructor_calldata: &'a [FieldElement],
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field2<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub contract_definition: &'a ContractClass,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            contract_address_salt: &self.contract_address_salt,
+        })?;
+        seq.serialize_element(&Field1 {
+            constructor_calldata: &self.constructor_calldata,
+        })?;
+        seq.serialize_element(&Field2 {
+            contract_definition: &self.contract_definition,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for AddDeployTransactionRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "UfeHex")]
+            pub contract_address_salt: &'a FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "[UfeHex]")]
+            pub constructor_calldata: &'a [FieldElement],
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field2<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub contract_definition: &'a ContractClass,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            contract_address_salt: self.contract_address_salt,
+        })?;
+        seq.serialize_element(&Field1 {
+            constructor_calldata: self.constructor_calldata,
+        })?;
+        seq.serialize_element(&Field2 {
+            contract_definition: self.contract_definition,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for AddDeployTransactionRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "UfeHex")]
+            pub contract_address_salt: FieldElement,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Vec<UfeHex>")]
+            pub constructor_calldata: Vec<FieldElement>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub contract_definition: ContractClass,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "UfeHex")]
+            pub contract_address_salt: FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Vec<UfeHex>")]
+            pub constructor_calldata: Vec<FieldElement>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field2 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub contract_definition: ContractClass,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 3 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field2 = serde_json::from_value::<Field2>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                contract_address_salt: field0.contract_address_salt,
+                constructor_calldata: field1.constructor_calldata,
+                contract_definition: field2.contract_definition,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                contract_address_salt: object.contract_address_salt,
+                constructor_calldata: object.constructor_calldata,
+                contract_definition: object.contract_definition,
+            })
+        }
+    }
+}
+
+impl Serialize for AddInvokeTransactionRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub function_invocation: &'a FunctionCall,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "[UfeHex]")]
+            pub signature: &'a [FieldElement],
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field2<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub max_fee: &'a u64,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field3<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub version: &'a u64,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            function_invocation: &self.function_invocation,
+        })?;
+        seq.serialize_element(&Field1 {
+            signature: &self.signature,
+        })?;
+        seq.serialize_element(&Field2 {
+            max_fee: &self.max_fee,
+        })?;
+        seq.serialize_element(&Field3 {
+            version: &self.version,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for AddInvokeTransactionRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub function_invocation: &'a FunctionCall,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "[UfeHex]")]
+            pub signature: &'a [FieldElement],
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field2<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub max_fee: &'a u64,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field3<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub version: &'a u64,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            function_invocation: self.function_invocation,
+        })?;
+        seq.serialize_element(&Field1 {
+            signature: self.signature,
+        })?;
+        seq.serialize_element(&Field2 {
+            max_fee: self.max_fee,
+        })?;
+        seq.serialize_element(&Field3 {
+            version: self.version,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for AddInvokeTransactionRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub function_invocation: FunctionCall,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Vec<UfeHex>")]
+            pub signature: Vec<FieldElement>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub max_fee: u64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub version: u64,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub function_invocation: FunctionCall,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Vec<UfeHex>")]
+            pub signature: Vec<FieldElement>,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field2 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub max_fee: u64,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field3 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "NumAsHex")]
+            pub version: u64,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 4 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field2 = serde_json::from_value::<Field2>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field3 = serde_json::from_value::<Field3>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                function_invocation: field0.function_invocation,
+                signature: field1.signature,
+                max_fee: field2.max_fee,
+                version: field3.version,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                function_invocation: object.function_invocation,
+                signature: object.signature,
+                max_fee: object.max_fee,
+                version: object.version,
+            })
+        }
+    }
+}
+
+impl Serialize for BlockHashAndNumberRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let seq = serializer.serialize_seq(Some(0))?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockHashAndNumberRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(elements) if elements.is_empty() => Ok(Self),
+            serde_json::Value::Object(fields) if fields.is_empty() => Ok(Self),
+            _ => Err(serde::de::Error::custom("invalid params")),
+        }
+    }
+}
+
+impl Serialize for BlockNumberRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let seq = serializer.serialize_seq(Some(0))?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockNumberRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(elements) if elements.is_empty() => Ok(Self),
+            serde_json::Value::Object(fields) if fields.is_empty() => Ok(Self),
+            _ => Err(serde::de::Error::custom("invalid params")),
+        }
+    }
+}
+
+impl Serialize for CallRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub request: &'a FunctionCall,
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            request: &self.request,
+        })?;
+        seq.serialize_element(&Field1 {
+            block_id: &self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for CallRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub request: &'a FunctionCall,
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            request: self.request,
+        })?;
+        seq.serialize_element(&Field1 {
+            block_id: self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CallRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub request: FunctionCall,
+            pub block_id: BlockId,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub request: FunctionCall,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            pub block_id: BlockId,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 2 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                request: field0.request,
+                block_id: field1.block_id,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                request: object.request,
+                block_id: object.block_id,
+            })
+        }
+    }
+}
+
+impl Serialize for ChainIdRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let seq = serializer.serialize_seq(Some(0))?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainIdRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(elements) if elements.is_empty() => Ok(Self),
+            serde_json::Value::Object(fields) if fields.is_empty() => Ok(Self),
+            _ => Err(serde::de::Error::custom("invalid params")),
+        }
+    }
+}
+
+impl Serialize for EstimateFeeRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub request: &'a InvokeTransaction,
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            request: &self.request,
+        })?;
+        seq.serialize_element(&Field1 {
+            block_id: &self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for EstimateFeeRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub request: &'a InvokeTransaction,
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            request: self.request,
+        })?;
+        seq.serialize_element(&Field1 {
+            block_id: self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for EstimateFeeRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub request: InvokeTransaction,
+            pub block_id: BlockId,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub request: InvokeTransaction,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            pub block_id: BlockId,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 2 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                request: field0.request,
+                block_id: field1.block_id,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                request: object.request,
+                block_id: object.block_id,
+            })
+        }
+    }
+}
+
+impl Serialize for GetBlockTransactionCountRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: &self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetBlockTransactionCountRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockTransactionCountRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub block_id: BlockId,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub block_id: BlockId,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                block_id: field0.block_id,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                block_id: object.block_id,
+            })
+        }
+    }
+}
+
+impl Serialize for GetBlockWithTxHashesRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: &self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetBlockWithTxHashesRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockWithTxHashesRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub block_id: BlockId,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub block_id: BlockId,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                block_id: field0.block_id,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                block_id: object.block_id,
+            })
+        }
+    }
+}
+
+impl Serialize for GetBlockWithTxsRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: &self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetBlockWithTxsRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockWithTxsRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub block_id: BlockId,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub block_id: BlockId,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                block_id: field0.block_id,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                block_id: object.block_id,
+            })
+        }
+    }
+}
+
+impl Serialize for GetClassAtRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: &self.block_id,
+        })?;
+        seq.serialize_element(&Field1 {
+            contract_address: &self.contract_address,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetClassAtRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: self.block_id,
+        })?;
+        seq.serialize_element(&Field1 {
+            contract_address: self.contract_address,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetClassAtRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub block_id: BlockId,
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: FieldElement,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub block_id: BlockId,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: FieldElement,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 2 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                block_id: field0.block_id,
+                contract_address: field1.contract_address,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                block_id: object.block_id,
+                contract_address: object.contract_address,
+            })
+        }
+    }
+}
+
+impl Serialize for GetClassHashAtRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: &self.block_id,
+        })?;
+        seq.serialize_element(&Field1 {
+            contract_address: &self.contract_address,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetClassHashAtRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: self.block_id,
+        })?;
+        seq.serialize_element(&Field1 {
+            contract_address: self.contract_address,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetClassHashAtRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub block_id: BlockId,
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: FieldElement,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub block_id: BlockId,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: FieldElement,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 2 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                block_id: field0.block_id,
+                contract_address: field1.contract_address,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                block_id: object.block_id,
+                contract_address: object.contract_address,
+            })
+        }
+    }
+}
+
+impl Serialize for GetClassRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub class_hash: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            class_hash: &self.class_hash,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetClassRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub class_hash: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            class_hash: self.class_hash,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetClassRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            #[serde_as(as = "UfeHex")]
+            pub class_hash: FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            #[serde_as(as = "UfeHex")]
+            pub class_hash: FieldElement,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                class_hash: field0.class_hash,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                class_hash: object.class_hash,
+            })
+        }
+    }
+}
+
+impl Serialize for GetEventsRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub filter: &'a EventFilterWithPage,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            filter: &self.filter,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetEventsRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub filter: &'a EventFilterWithPage,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            filter: self.filter,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetEventsRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub filter: EventFilterWithPage,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub filter: EventFilterWithPage,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                filter: field0.filter,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                filter: object.filter,
+            })
+        }
+    }
+}
+
+impl Serialize for GetNonceRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            contract_address: &self.contract_address,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetNonceRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            contract_address: self.contract_address,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetNonceRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: FieldElement,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                contract_address: field0.contract_address,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                contract_address: object.contract_address,
+            })
+        }
+    }
+}
+
+impl Serialize for GetStateUpdateRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: &self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetStateUpdateRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetStateUpdateRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub block_id: BlockId,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub block_id: BlockId,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                block_id: field0.block_id,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                block_id: object.block_id,
+            })
+        }
+    }
+}
+
+impl Serialize for GetStorageAtRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: &'a FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub key: &'a FieldElement,
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field2<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            contract_address: &self.contract_address,
+        })?;
+        seq.serialize_element(&Field1 { key: &self.key })?;
+        seq.serialize_element(&Field2 {
+            block_id: &self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetStorageAtRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: &'a FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub key: &'a FieldElement,
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field2<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            contract_address: self.contract_address,
+        })?;
+        seq.serialize_element(&Field1 { key: self.key })?;
+        seq.serialize_element(&Field2 {
+            block_id: self.block_id,
+        })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetStorageAtRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: FieldElement,
+            #[serde_as(as = "UfeHex")]
+            pub key: FieldElement,
+            pub block_id: BlockId,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            #[serde_as(as = "UfeHex")]
+            pub contract_address: FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            #[serde_as(as = "UfeHex")]
+            pub key: FieldElement,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field2 {
+            pub block_id: BlockId,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 3 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field2 = serde_json::from_value::<Field2>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                contract_address: field0.contract_address,
+                key: field1.key,
+                block_id: field2.block_id,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                contract_address: object.contract_address,
+                key: object.key,
+                block_id: object.block_id,
+            })
+        }
+    }
+}
+
+impl Serialize for GetTransactionByBlockIdAndIndexRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            pub index: &'a u64,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: &self.block_id,
+        })?;
+        seq.serialize_element(&Field1 { index: &self.index })?;
+
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for GetTransactionByBlockIdAndIndexRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            pub block_id: &'a BlockId,
+        }
+
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field1<'a> {
+            pub index: &'a u64,
+        }
+
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+
+        seq.serialize_element(&Field0 {
+            block_id: self.block_id,
+        })?;
+        seq.serialize_element(&Field1 { index: self.index })?;
+
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetTransactionByBlockIdAndIndexRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            pub block_id: BlockId,
+            pub index: u64,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            pub block_id: BlockId,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field1 {
+            pub index: u64,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 2 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+            let field1 = serde_json::from_value::<Field1>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                block_id: field0.block_id,
+                index: field1.index,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                block_id: object.block_id,
+                index: object.index,
+            })
+        }
+    }
+}
+
+impl Serialize for GetTransactionByHashRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub transaction_hash: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry(
+            "transaction_hash",
+            &Field0 {
+                transaction_hash: &self.transaction_hash,
+            },
+        )?;
+
+        map.end()
+    }
+}
+
+impl<'a> Serialize for GetTransactionByHashRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub transaction_hash: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry(
+            "transaction_hash",
+            &Field0 {
+                transaction_hash: self.transaction_hash,
+            },
+        )?;
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetTransactionByHashRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            #[serde_as(as = "UfeHex")]
+            pub transaction_hash: FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            #[serde_as(as = "UfeHex")]
+            pub transaction_hash: FieldElement,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                transaction_hash: field0.transaction_hash,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                transaction_hash: object.transaction_hash,
+            })
+        }
+    }
+}
+
+impl Serialize for GetTransactionReceiptRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub transaction_hash: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry(
+            "transaction_hash",
+            &Field0 {
+                transaction_hash: &self.transaction_hash,
+            },
+        )?;
+
+        map.end()
+    }
+}
+
+impl<'a> Serialize for GetTransactionReceiptRequestRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_as]
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Field0<'a> {
+            #[serde_as(as = "UfeHex")]
+            pub transaction_hash: &'a FieldElement,
+        }
+
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry(
+            "transaction_hash",
+            &Field0 {
+                transaction_hash: self.transaction_hash,
+            },
+        )?;
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetTransactionReceiptRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct AsObject {
+            #[serde_as(as = "UfeHex")]
+            pub transaction_hash: FieldElement,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct Field0 {
+            #[serde_as(as = "UfeHex")]
+            pub transaction_hash: FieldElement,
+        }
+
+        let temp = serde_json::Value::deserialize(deserializer)?;
+
+        if let Ok(elements) = Vec::<serde_json::Value>::deserialize(&temp) {
+            if elements.len() > 1 {
+                return Err(serde::de::Error::custom("invalid sequence length"));
+            }
+            let mut elements = elements.into_iter();
+
+            let field0 = serde_json::from_value::<Field0>(
+                elements
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("invalid sequence length"))?,
+            )
+            .map_err(|err| serde::de::Error::custom(format!("failed to parse element: {}", err)))?;
+
+            Ok(Self {
+                transaction_hash: field0.transaction_hash,
+            })
+        } else {
+            let object = AsObject::deserialize(&temp)
+                .map_err(|err| serde::de::Error::custom(format!("invalid params: {}", err)))?;
+
+            Ok(Self {
+                transaction_hash: object.transaction_hash,
+            })
+        }
+    }
+}
+
+impl Serialize for PendingTransactionsRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let seq = serializer.serialize_seq(Some(0))?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for PendingTransactionsRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(elements) if elements.is_empty() => Ok(Self),
+            serde_json::Value::Object(fields) if fields.is_empty() => Ok(Self),
+            _ => Err(serde::de::Error::custom("invalid params")),
+        }
+    }
+}
+
+impl Serialize for ProtocolVersionRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let seq = serializer.serialize_seq(Some(0))?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolVersionRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(elements) if elements.is_empty() => Ok(Self),
+            serde_json::Value::Object(fields) if fields.is_empty() => Ok(Self),
+            _ => Err(serde::de::Error::custom("invalid params")),
+        }
+    }
+}
+
+impl Serialize for SyncingRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let seq = serializer.serialize_seq(Some(0))?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SyncingRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(elements) if elements.is_empty() => Ok(Self),
+            serde_json::Value::Object(fields) if fields.is_empty() => Ok(Self),
+            _ => Err(serde::de::Error::custom("invalid params")),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+pub mod byte_array {
+    pub mod base64 {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer, T: AsRef<[u8]>>(
+            value: T,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(value.as_ref())
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<u8>, D::Error> {
+            Vec::<u8>::deserialize(deserializer)
+        }
+    }
+}
+
+pub mod unsigned_field_element {
+    use serde::{Deserializer, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+
+    use crate::types::FieldElement;
+
+    pub struct UfeHex;
+
+    impl SerializeAs<FieldElement> for UfeHex {
+        fn serialize_as<S: Serializer>(
+            value: &FieldElement,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serde::Serialize::serialize(value, serializer)
+        }
+    }
+
+    impl<'de> DeserializeAs<'de, FieldElement> for UfeHex {
+        fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<FieldElement, D::Error> {
+            serde::Deserialize::deserialize(deserializer)
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+mod codegen;
+pub use codegen::*;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FieldElement([u8; 32]);
+
+#[derive(Debug)]
+pub struct FromByteArrayError;
+
+#[derive(Debug)]
+pub struct FromStrError;
+
+impl FieldElement {
+    pub fn from_bytes_be(bytes: &[u8; 32]) -> Result<Self, FromByteArrayError> {
+        Ok(Self(*bytes))
+    }
+
+    pub fn from_hex_be(value: &str) -> Result<Self, FromStrError> {
+        let value = value.trim_start_matches("0x");
+        if value.len() > 64 {
+            return Err(FromStrError);
+        }
+
+        let mut bytes = [0u8; 32];
+        for (ind, digit) in value.chars().rev().enumerate() {
+            let digit = digit.to_digit(16).ok_or(FromStrError)? as u8;
+            bytes[31 - ind / 2] |= digit << (4 * (ind % 2));
+        }
+
+        Ok(Self(bytes))
+    }
+}
+
+/// Stands in for the types implemented by hand in `starknet-rs`.
+macro_rules! manual_types {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+            #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+            pub struct $name(String);
+        )*
+    };
+}
+
+manual_types!(
+    BlockId,
+    BroadcastedDeclareTransaction,
+    BroadcastedInvokeTransaction,
+    BroadcastedTransaction,
+    ContractClass,
+    DeclareTransaction,
+    InvokeTransaction,
+    L1Address,
+    LegacyContractAbiEntry,
+    PendingTransactionReceipt,
+    Transaction,
+    TransactionReceipt,
+);
+
+mod serde_impls {
+    use serde::{Deserializer, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+
+    pub struct NumAsHex;
+
+    impl SerializeAs<u64> for NumAsHex {
+        fn serialize_as<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&format!("{value:#x}"))
+        }
+    }
+
+    impl<'de> DeserializeAs<'de, u64> for NumAsHex {
+        fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+            let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+            u64::from_str_radix(value.trim_start_matches("0x"), 16)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Serializes and deserializes `#[serde(with)]` fields wrapped in arrays and options through their
+//! generated `serde_as` adapters. Copied into the stub crate by `tests/compile.rs` along with code
+//! generated from `tests/specs/synthetic.json`.
+
+use serde_json::json;
+use starknet_core::types::*;
+
+#[test]
+fn base64_fields_round_trip() {
+    let data = EncodedData {
+        chunks: vec![vec![1, 2], vec![]],
+        extra: Some(vec![3]),
+    };
+
+    let serialized = serde_json::to_value(&data).unwrap();
+    assert_eq!(serialized, json!({ "chunks": [[1, 2], []], "extra": [3] }));
+
+    let decoded = serde_json::from_value::<EncodedData>(serialized).unwrap();
+    assert_eq!(decoded.chunks, data.chunks);
+    assert_eq!(decoded.extra, data.extra);
+}
+
+#[test]
+fn missing_optional_base64_fields_are_none() {
+    let decoded = serde_json::from_value::<EncodedData>(json!({ "chunks": [] })).unwrap();
+
+    assert!(decoded.chunks.is_empty());
+    assert_eq!(decoded.extra, None);
+    assert_eq!(
+        serde_json::to_value(&decoded).unwrap(),
+        json!({ "chunks": [] })
+    );
+}
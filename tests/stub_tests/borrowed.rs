@@ -0,0 +1,50 @@
+//! Deserializes the generated `Borrowed` types from JSON input. Copied into the stub
+//! crate by `tests/compile.rs` along with code generated from `tests/specs/synthetic.json`.
+
+use std::borrow::Cow;
+
+use starknet_core::types::*;
+
+const LABELED_VALUE: &str = r#"{
+    "key": "0x1",
+    "name": "name",
+    "aliases": ["first", "second"],
+    "note": "note",
+    "keys": ["0x2", "0x3"]
+}"#;
+
+#[test]
+fn borrows_from_borrowed_input() {
+    let value = serde_json::from_str::<LabeledValueBorrowed>(LABELED_VALUE).unwrap();
+
+    assert_eq!(value.key.as_str(), "0x1");
+    assert!(matches!(value.name, Cow::Borrowed("name")));
+    assert!(value
+        .aliases
+        .iter()
+        .all(|alias| matches!(alias, Cow::Borrowed(_))));
+    assert!(matches!(value.note, Some(Cow::Borrowed("note"))));
+    assert_eq!(value.keys[1].as_str(), "0x3");
+}
+
+#[test]
+fn copies_escaped_strings() {
+    // Escaped strings can't be borrowed from the input, so they have to be copied
+    let value = serde_json::from_str::<LabeledValueBorrowed>(
+        r#"{
+            "key": "0x\u0031",
+            "name": "\u006eame",
+            "aliases": ["\u0066irst"],
+            "keys": []
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        value.key.parse().unwrap(),
+        FieldElement::from_hex_be("0x1").unwrap()
+    );
+    assert!(matches!(&value.name, Cow::Owned(name) if name == "name"));
+    assert!(matches!(&value.aliases[0], Cow::Owned(alias) if alias == "first"));
+    assert_eq!(value.note, None);
+}
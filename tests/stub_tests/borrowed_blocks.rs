@@ -0,0 +1,66 @@
+//! Deserializes blocks into their `Borrowed` variants through the `oneOf` enums of their
+//! transactions. Copied into the stub crate by `tests/compile.rs` along with code generated for
+//! `0.3.0` with `--oneof-enums`.
+
+use starknet_core::types::*;
+
+const BLOCK: &str = r#"{
+    "status": "ACCEPTED_ON_L2",
+    "block_hash": "0x1",
+    "parent_hash": "0x2",
+    "block_number": 3,
+    "new_root": "0x4",
+    "timestamp": 5,
+    "sequencer_address": "0x6",
+    "transactions": [
+        {
+            "transaction_hash": "0x7",
+            "type": "INVOKE",
+            "version": "0x1",
+            "max_fee": "0x8",
+            "signature": ["0x9"],
+            "nonce": "0xa",
+            "sender_address": "0xb",
+            "calldata": ["0xc", "0xd"]
+        },
+        {
+            "transaction_hash": "0xe",
+            "type": "L1_HANDLER",
+            "version": "0x0",
+            "nonce": "0xf",
+            "contract_address": "0x10",
+            "entry_point_selector": "0x11",
+            "calldata": []
+        }
+    ]
+}"#;
+
+/// Whether `value` points into `input` rather than to a copy.
+fn borrows_from(input: &str, value: &str) -> bool {
+    input.as_bytes().as_ptr_range().contains(&value.as_ptr())
+}
+
+#[test]
+fn transactions_borrow_from_input() {
+    let block = serde_json::from_str::<BlockWithTxsBorrowed>(BLOCK).unwrap();
+
+    assert!(borrows_from(BLOCK, block.block_hash.as_str()));
+    match &block.transactions[0] {
+        TransactionBorrowed::Invoke(InvokeTransactionBorrowed::V1(invoke)) => {
+            assert_eq!(invoke.sender_address.as_str(), "0xb");
+            assert_eq!(invoke.calldata[1].as_str(), "0xd");
+            assert!(borrows_from(BLOCK, invoke.transaction_hash.as_str()));
+        }
+        other => panic!("unexpected transaction: {other:?}"),
+    }
+    match &block.transactions[1] {
+        TransactionBorrowed::L1Handler(l1_handler) => {
+            assert_eq!(
+                l1_handler.transaction_hash.parse().unwrap(),
+                FieldElement::from_hex_be("0xe").unwrap()
+            );
+            assert!(borrows_from(BLOCK, l1_handler.contract_address.as_str()));
+        }
+        other => panic!("unexpected transaction: {other:?}"),
+    }
+}
@@ -0,0 +1,120 @@
+//! Runs the generated client against an in-memory transport. Copied into the stub crate by
+//! `tests/compile.rs` along with code generated with `--client`.
+
+use serde_json::{json, Value};
+use starknet_core::types::*;
+
+mod common;
+mod transport;
+
+use common::block_on;
+use transport::InMemoryTransport;
+
+#[test]
+fn decodes_results() {
+    let client = JsonRpcClient::new(InMemoryTransport(|request: Value| {
+        assert_eq!(
+            request,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "method": "starknet_blockNumber",
+                "params": [],
+            })
+        );
+
+        json!({ "jsonrpc": "2.0", "id": 0, "result": 42 })
+    }));
+
+    assert_eq!(block_on(client.block_number()).unwrap(), 42);
+}
+
+#[test]
+fn sends_params_by_position_or_name() {
+    let client = JsonRpcClient::new(InMemoryTransport(|request: Value| {
+        let params = match request["method"].as_str().unwrap() {
+            "starknet_getBlockTransactionCount" => json!([""]),
+            "starknet_getTransactionByHash" => {
+                json!({ "transaction_hash": FieldElement::default() })
+            }
+            method => panic!("unexpected method {method}"),
+        };
+        assert_eq!(request["params"], params);
+
+        json!({ "jsonrpc": "2.0", "id": request["id"], "result": 1 })
+    }));
+
+    assert_eq!(
+        block_on(client.get_block_transaction_count(&BlockId::default()))
+            .unwrap()
+            .0,
+        1
+    );
+    // The stub transaction type is a string, so decoding the result fails
+    assert!(matches!(
+        block_on(client.get_transaction_by_hash(&FieldElement::default())),
+        Err(JsonRpcClientError::Json(_))
+    ));
+}
+
+#[test]
+fn decodes_errors() {
+    let client = JsonRpcClient::new(InMemoryTransport(|request: Value| {
+        let code = match request["id"].as_u64().unwrap() {
+            0 => 24,
+            _ => -32601,
+        };
+
+        json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "error": { "code": code, "message": "error", "data": "details" },
+        })
+    }));
+
+    assert!(matches!(
+        block_on(client.get_block_transaction_count(&BlockId::default())),
+        Err(JsonRpcClientError::Starknet {
+            error: StarknetError::BlockNotFound,
+            data: Some(Value::String(data)),
+        }) if data == "details"
+    ));
+    assert!(matches!(
+        block_on(client.block_number()),
+        Err(JsonRpcClientError::JsonRpc(JsonRpcError {
+            code: -32601,
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn rejects_mismatched_response_ids() {
+    let client = JsonRpcClient::new(InMemoryTransport(
+        |_| json!({ "jsonrpc": "2.0", "id": 100, "result": 42 }),
+    ));
+
+    assert!(matches!(
+        block_on(client.block_number()),
+        Err(JsonRpcClientError::Json(_))
+    ));
+}
+
+#[test]
+fn decodes_errors_with_null_ids() {
+    let client = JsonRpcClient::new(InMemoryTransport(|_| {
+        json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": { "code": -32700, "message": "Parse error" },
+        })
+    }));
+
+    assert!(matches!(
+        block_on(client.block_number()),
+        Err(JsonRpcClientError::JsonRpc(JsonRpcError {
+            code: -32700,
+            ..
+        }))
+    ));
+}
@@ -0,0 +1,30 @@
+//! Helpers shared by the stub tests. Copied into the stub crate by `tests/compile.rs` along with
+//! each test.
+
+// Each test only uses some of the helpers
+#![allow(dead_code)]
+
+use std::{
+    future::Future,
+    task::{Context, Poll, Waker},
+};
+
+use starknet_core::types::FieldElement;
+
+pub fn felt(value: u8) -> FieldElement {
+    let mut bytes = [0u8; 32];
+    bytes[31] = value;
+    FieldElement::from_bytes_be(&bytes).unwrap()
+}
+
+/// Polls `future` to completion, which is immediate for in-memory transports and the mock
+/// provider.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
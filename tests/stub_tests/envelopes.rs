@@ -0,0 +1,209 @@
+//! Serializes and deserializes the generated envelopes. Copied into the stub crate by
+//! `tests/compile.rs` along with code generated with `--jsonrpc-envelopes`.
+
+use serde_json::{json, Value};
+use starknet_core::types::*;
+
+fn response(id: i64, result: Value) -> JsonRpcResponse {
+    JsonRpcResponse::Success {
+        id: JsonRpcId::Number(id),
+        result,
+    }
+}
+
+#[test]
+fn requests_round_trip() {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": "nonce",
+        "method": "starknet_getNonce",
+        "params": ["latest", FieldElement::default()],
+    });
+
+    let decoded = serde_json::from_value::<JsonRpcRequest>(request.clone()).unwrap();
+    assert_eq!(decoded.id, Some(JsonRpcId::String(String::from("nonce"))));
+    assert_eq!(decoded.data.method(), JsonRpcMethod::GetNonce);
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), request);
+}
+
+#[test]
+fn requests_without_params_round_trip() {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "starknet_blockNumber",
+        "params": [],
+    });
+
+    let decoded = serde_json::from_value::<JsonRpcRequest>(request.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), request);
+}
+
+#[test]
+fn notifications_round_trip() {
+    let notification = json!({
+        "jsonrpc": "2.0",
+        "method": "starknet_blockNumber",
+        "params": [],
+    });
+
+    let decoded = serde_json::from_value::<JsonRpcRequest>(notification.clone()).unwrap();
+    assert_eq!(decoded.id, None);
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), notification);
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": null,
+        "method": "starknet_blockNumber",
+        "params": [],
+    });
+    let decoded = serde_json::from_value::<JsonRpcRequest>(request).unwrap();
+    assert_eq!(decoded.id, Some(JsonRpcId::Null));
+}
+
+#[test]
+fn negative_ids_round_trip() {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": -1,
+        "method": "starknet_blockNumber",
+        "params": [],
+    });
+
+    let decoded = serde_json::from_value::<JsonRpcRequest>(request.clone()).unwrap();
+    assert_eq!(decoded.id, Some(JsonRpcId::Number(-1)));
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), request);
+}
+
+#[test]
+fn rejects_other_jsonrpc_versions() {
+    assert!(serde_json::from_value::<JsonRpcRequest>(json!({
+        "jsonrpc": "1.0",
+        "id": 1,
+        "method": "starknet_blockNumber",
+        "params": [],
+    }))
+    .is_err());
+    assert!(serde_json::from_value::<JsonRpcResponse>(json!({
+        "jsonrpc": "1.0",
+        "id": 1,
+        "result": 42,
+    }))
+    .is_err());
+}
+
+#[test]
+fn keeps_null_results_apart_from_missing_ones() {
+    assert!(matches!(
+        serde_json::from_value::<JsonRpcResponse>(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": null,
+        })),
+        Ok(JsonRpcResponse::Success {
+            result: Value::Null,
+            ..
+        })
+    ));
+    assert!(serde_json::from_value::<JsonRpcResponse>(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+    }))
+    .is_err());
+}
+
+#[test]
+fn decodes_errors_with_null_ids() {
+    let error = json!({
+        "jsonrpc": "2.0",
+        "id": null,
+        "error": { "code": -32700, "message": "Parse error" },
+    });
+
+    let decoded = serde_json::from_value::<JsonRpcResponse>(error.clone()).unwrap();
+    assert!(matches!(
+        &decoded,
+        JsonRpcResponse::Error {
+            id: JsonRpcId::Null,
+            error: JsonRpcError { code: -32700, .. },
+        }
+    ));
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), error);
+}
+
+#[test]
+fn matches_batch_responses_by_id() {
+    let batch = serde_json::from_value::<JsonRpcBatchRequest>(json!([
+        { "jsonrpc": "2.0", "id": 0, "method": "starknet_blockNumber", "params": [] },
+        { "jsonrpc": "2.0", "id": 1, "method": "starknet_chainId", "params": [] },
+    ]))
+    .unwrap();
+
+    let decoded = JsonRpcBatchResponse(vec![
+        response(1, serde_json::to_value(FieldElement::default()).unwrap()),
+        response(0, json!(42)),
+    ])
+    .decode(&batch)
+    .unwrap();
+
+    assert!(matches!(
+        decoded.as_slice(),
+        [
+            JsonRpcResponse::Success {
+                id: JsonRpcId::Number(0),
+                result: JsonRpcResult::BlockNumber(42),
+            },
+            JsonRpcResponse::Success {
+                id: JsonRpcId::Number(1),
+                result: JsonRpcResult::ChainId(_),
+            },
+        ]
+    ));
+}
+
+#[test]
+fn skips_notifications_in_batches() {
+    let batch = serde_json::from_value::<JsonRpcBatchRequest>(json!([
+        { "jsonrpc": "2.0", "method": "starknet_chainId", "params": [] },
+        { "jsonrpc": "2.0", "id": 0, "method": "starknet_blockNumber", "params": [] },
+    ]))
+    .unwrap();
+
+    let decoded = JsonRpcBatchResponse(vec![response(0, json!(42))])
+        .decode(&batch)
+        .unwrap();
+
+    assert!(matches!(
+        decoded.as_slice(),
+        [JsonRpcResponse::Success {
+            result: JsonRpcResult::BlockNumber(42),
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn rejects_batches_with_missing_responses() {
+    let batch = serde_json::from_value::<JsonRpcBatchRequest>(json!([
+        { "jsonrpc": "2.0", "id": 0, "method": "starknet_blockNumber", "params": [] },
+        { "jsonrpc": "2.0", "id": 1, "method": "starknet_blockNumber", "params": [] },
+    ]))
+    .unwrap();
+
+    assert!(JsonRpcBatchResponse(vec![response(1, json!(42))])
+        .decode(&batch)
+        .is_err());
+}
+
+#[test]
+fn syncing_response_only_accepts_false() {
+    assert!(matches!(
+        serde_json::from_value::<SyncingResponse>(json!(false)),
+        Ok(SyncingResponse::False)
+    ));
+    assert!(serde_json::from_value::<SyncingResponse>(json!(true)).is_err());
+    assert_eq!(
+        serde_json::to_value(SyncingResponse::False).unwrap(),
+        json!(false)
+    );
+}
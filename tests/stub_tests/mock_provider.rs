@@ -0,0 +1,66 @@
+//! Runs the generated client against the generated `MockProvider`. Copied into the stub crate by
+//! `tests/compile.rs` along with code generated with `--mock-provider`.
+
+use starknet_core::types::*;
+
+mod common;
+
+use common::block_on;
+
+#[test]
+fn answers_from_expectations_in_order() {
+    let mut mock = MockProvider::new();
+    mock.expect_block_number().returning(|_| Ok(1));
+    mock.expect_block_number().returning(|_| Ok(2));
+    mock.expect_get_block_transaction_count()
+        .with(|request| request.block_id == BlockId::default())
+        .returning(|_| Err(StarknetError::BlockNotFound));
+    let client = JsonRpcClient::new(mock);
+
+    assert_eq!(block_on(client.block_number()).unwrap(), 1);
+    assert_eq!(block_on(client.block_number()).unwrap(), 2);
+    assert!(matches!(
+        block_on(client.get_block_transaction_count(&BlockId::default())),
+        Err(JsonRpcClientError::Starknet {
+            error: StarknetError::BlockNotFound,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn rejects_unexpected_calls() {
+    let client = JsonRpcClient::new(MockProvider::new());
+
+    assert!(matches!(
+        block_on(client.block_number()),
+        Err(JsonRpcClientError::Transport(MockError::UnexpectedCall(
+            JsonRpcMethod::BlockNumber
+        )))
+    ));
+}
+
+#[test]
+fn rejects_unmatched_requests() {
+    let mut mock = MockProvider::new();
+    mock.expect_get_block_transaction_count()
+        .with(|_| false)
+        .returning(|_| unreachable!());
+    let client = JsonRpcClient::new(mock);
+
+    assert!(matches!(
+        block_on(client.get_block_transaction_count(&BlockId::default())),
+        Err(JsonRpcClientError::Transport(MockError::UnmatchedRequest(
+            JsonRpcMethod::GetBlockTransactionCount
+        )))
+    ));
+}
+
+#[test]
+#[should_panic(expected = "unsatisfied expectations for starknet_blockNumber")]
+fn panics_on_unsatisfied_expectations() {
+    let mut mock = MockProvider::new();
+    mock.expect_block_number().returning(|_| Ok(1));
+
+    drop(mock);
+}
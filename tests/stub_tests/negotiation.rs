@@ -0,0 +1,79 @@
+//! Detects the spec version of in-memory nodes. Copied into the stub crate by `tests/compile.rs`
+//! along with code generated for several versions with `--version-negotiation`.
+
+use serde_json::{json, Value};
+use starknet_core::types::{
+    negotiation::{VersionNegotiationError, VersionedClient},
+    shared::JsonRpcTransport,
+    v0_1, v0_2, v0_3,
+};
+
+mod common;
+mod transport;
+
+use common::block_on;
+use transport::InMemoryTransport;
+
+/// Node implementing `methods`, answering `starknet_specVersion` with `spec_version` if any.
+fn node(
+    spec_version: Option<&'static str>,
+    methods: &'static [&'static str],
+) -> InMemoryTransport<impl Fn(Value) -> Value + Send + Sync> {
+    InMemoryTransport(move |request: Value| {
+        let method = request["method"].as_str().unwrap();
+        assert!(
+            !method.starts_with("starknet_add"),
+            "probed write method `{method}`"
+        );
+
+        match spec_version {
+            Some(spec_version) if method == "starknet_specVersion" => {
+                json!({ "jsonrpc": "2.0", "id": request["id"], "result": spec_version })
+            }
+            _ if methods.contains(&method) => json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": { "code": -32602, "message": "Invalid params" },
+            }),
+            _ => json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": { "code": -32601, "message": "Method not found" },
+            }),
+        }
+    })
+}
+
+#[test]
+fn spec_version_constants() {
+    assert_eq!(v0_1::SPEC_VERSION, "0.1.0");
+    assert_eq!(v0_2::SPEC_VERSION, "0.2.1");
+    assert_eq!(v0_3::SPEC_VERSION, "0.3.0");
+}
+
+#[test]
+fn uses_reported_spec_version() {
+    let client = block_on(VersionedClient::negotiate(node(Some("0.3.1"), &[]))).unwrap();
+    assert!(matches!(client, VersionedClient::V0_3(_)));
+    assert_eq!(client.spec_version(), "0.3.0");
+
+    let error = block_on(VersionedClient::negotiate(node(Some("0.9.0"), &[]))).unwrap_err();
+    assert!(matches!(
+        error,
+        VersionNegotiationError::UnsupportedVersion(version) if version == "0.9.0"
+    ));
+}
+
+#[test]
+fn detects_spec_version_from_read_methods() {
+    let client = block_on(VersionedClient::negotiate(node(
+        None,
+        &["starknet_protocolVersion"],
+    )))
+    .unwrap();
+    assert!(matches!(client, VersionedClient::V0_1(_)));
+
+    // Versions only differing in write methods can't be told apart without probing them
+    let client = block_on(VersionedClient::negotiate(node(None, &[]))).unwrap();
+    assert!(matches!(client, VersionedClient::V0_3(_)));
+}
@@ -0,0 +1,75 @@
+//! Deserializes the generated request types from both param forms. Copied into the stub crate by
+//! `tests/compile.rs` along with code generated from `tests/specs/synthetic.json`.
+
+use serde_json::{json, Value};
+use starknet_core::types::*;
+
+mod common;
+
+use common::felt;
+
+fn round_trip(request: &TestSetValueRequest) -> TestSetValueRequest {
+    serde_json::from_value(serde_json::to_value(request).unwrap()).unwrap()
+}
+
+#[test]
+fn accepts_objects() {
+    let request = serde_json::from_value::<TestSetValueRequest>(json!({
+        "key": felt(1),
+        "value": 2,
+        "label": "label",
+    }))
+    .unwrap();
+
+    assert_eq!(request.key, felt(1));
+    assert_eq!(request.value, 2);
+    assert_eq!(request.label.as_deref(), Some("label"));
+    assert_eq!(request.count, None);
+}
+
+#[test]
+fn reports_invalid_objects() {
+    let err = serde_json::from_value::<TestSetValueRequest>(json!({ "value": 2 })).unwrap_err();
+
+    assert!(err.to_string().contains("missing field `key`"), "{err}");
+}
+
+#[test]
+fn accepts_arrays_without_trailing_optional_params() {
+    let request =
+        serde_json::from_value::<TestSetValueRequest>(json!([felt(1), 2, "label"])).unwrap();
+    assert_eq!(request.label.as_deref(), Some("label"));
+    assert_eq!(request.count, None);
+
+    let request = serde_json::from_value::<TestSetValueRequest>(json!([felt(1), 2])).unwrap();
+    assert_eq!(request.label, None);
+    assert_eq!(request.count, None);
+
+    assert!(serde_json::from_value::<TestSetValueRequest>(json!([felt(1)])).is_err());
+}
+
+#[test]
+fn rejects_overlong_arrays() {
+    assert!(
+        serde_json::from_value::<TestSetValueRequest>(json!([felt(1), 2, "label", 3, 4])).is_err()
+    );
+}
+
+#[test]
+fn requests_round_trip() {
+    let request = TestSetValueRequest {
+        key: felt(1),
+        value: 2,
+        label: None,
+        count: Some(3),
+    };
+
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert_eq!(serialized, json!([felt(1), 2, Value::Null, 3]));
+
+    let decoded = round_trip(&request);
+    assert_eq!(decoded.key, request.key);
+    assert_eq!(decoded.value, request.value);
+    assert_eq!(decoded.label, request.label);
+    assert_eq!(decoded.count, request.count);
+}
@@ -0,0 +1,121 @@
+//! Records exchanges with an in-memory node and replays them. Copied into the stub crate by
+//! `tests/compile.rs` along with code generated with `--record-replay`.
+
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+use starknet_core::types::*;
+
+mod common;
+mod transport;
+
+use common::block_on;
+use transport::InMemoryTransport;
+
+fn fixture_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "starknet-codegen-{}-{}.json",
+        std::process::id(),
+        name
+    ))
+}
+
+/// Node answering `starknet_blockNumber` with increasing numbers and failing everything else.
+fn node() -> InMemoryTransport<impl Fn(Value) -> Value + Send + Sync> {
+    let block_number = std::sync::atomic::AtomicU64::new(100);
+
+    InMemoryTransport(
+        move |request: Value| match request["method"].as_str().unwrap() {
+            "starknet_blockNumber" => json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": block_number.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+            }),
+            _ => json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": { "code": 24, "message": "Block not found" },
+            }),
+        },
+    )
+}
+
+#[test]
+fn replays_recorded_exchanges() {
+    let path = fixture_path("replay");
+
+    let client = JsonRpcClient::new(RecordingTransport::new(node(), &path));
+    assert_eq!(block_on(client.block_number()).unwrap(), 100);
+    assert_eq!(block_on(client.block_number()).unwrap(), 101);
+    assert!(matches!(
+        block_on(client.get_block_transaction_count(&BlockId::default())),
+        Err(JsonRpcClientError::Starknet {
+            error: StarknetError::BlockNotFound,
+            ..
+        })
+    ));
+    assert_eq!(client.transport().fixture().exchanges.len(), 3);
+
+    let client = JsonRpcClient::new(ReplayTransport::load(&path).unwrap());
+    assert!(matches!(
+        block_on(client.get_block_transaction_count(&BlockId::default())),
+        Err(JsonRpcClientError::Starknet {
+            error: StarknetError::BlockNotFound,
+            ..
+        })
+    ));
+    assert_eq!(block_on(client.block_number()).unwrap(), 100);
+    assert_eq!(block_on(client.block_number()).unwrap(), 101);
+    assert!(matches!(
+        block_on(client.block_number()),
+        Err(JsonRpcClientError::Transport(
+            FixtureError::NoRecordedExchange(JsonRpcMethod::BlockNumber)
+        ))
+    ));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn matches_params_structurally() {
+    let replay = ReplayTransport::new(JsonRpcFixture {
+        exchanges: vec![RecordedExchange {
+            method: JsonRpcMethod::GetBlockTransactionCount,
+            params: json!([""]),
+            response: RecordedResponse::Result(json!(5)),
+        }],
+    });
+
+    // Named params are normalized to the positional form of the request type
+    let response = block_on(replay.send(json!({
+        "jsonrpc": "2.0",
+        "id": "a",
+        "method": "starknet_getBlockTransactionCount",
+        "params": { "block_id": "" },
+    })))
+    .unwrap();
+
+    assert_eq!(
+        response,
+        json!({ "jsonrpc": "2.0", "id": "a", "result": 5 })
+    );
+}
+
+#[test]
+fn rejects_results_not_matching_result_types() {
+    let path = fixture_path("invalid");
+    let transport = RecordingTransport::new(
+        InMemoryTransport(
+            |request: Value| json!({ "jsonrpc": "2.0", "id": request["id"], "result": "not a number" }),
+        ),
+        &path,
+    );
+
+    assert!(matches!(
+        block_on(JsonRpcClient::new(transport).block_number()),
+        Err(JsonRpcClientError::Transport(RecordingError::Fixture(
+            FixtureError::Json(_)
+        )))
+    ));
+    assert!(!path.exists());
+}
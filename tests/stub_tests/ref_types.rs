@@ -0,0 +1,59 @@
+//! Serializes the generated `Ref` types. Copied into the stub crate by `tests/compile.rs` along
+//! with code generated from `tests/specs/synthetic.json`.
+
+use starknet_core::types::*;
+
+fn labeled_value(name: &str) -> LabeledValue {
+    LabeledValue {
+        key: FieldElement::default(),
+        name: String::from(name),
+        aliases: vec![String::from("alias")],
+        note: None,
+        keys: vec![FieldElement::default()],
+    }
+}
+
+fn labeled_value_ref(value: &LabeledValue) -> LabeledValueRef<'_> {
+    LabeledValueRef {
+        key: &value.key,
+        name: value.name.as_str().into(),
+        aliases: value.aliases.as_slice().into(),
+        note: value.note.as_deref(),
+        keys: value.keys.as_slice().into(),
+    }
+}
+
+#[test]
+fn nested_ref_types_serialize_like_owned_types() {
+    let pair = LabeledPair {
+        first: labeled_value("first"),
+        second: Some(labeled_value("second")),
+    };
+
+    let pair_ref = LabeledPairRef {
+        first: labeled_value_ref(&pair.first),
+        second: pair.second.as_ref().map(labeled_value_ref),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&pair_ref).unwrap(),
+        serde_json::to_value(&pair).unwrap()
+    );
+}
+
+#[test]
+fn missing_optional_ref_types_are_skipped() {
+    let pair = LabeledPair {
+        first: labeled_value("first"),
+        second: None,
+    };
+
+    let pair_ref = LabeledPairRef {
+        first: labeled_value_ref(&pair.first),
+        second: None,
+    };
+
+    let serialized = serde_json::to_value(&pair_ref).unwrap();
+    assert!(serialized.get("second").is_none());
+    assert_eq!(serialized, serde_json::to_value(&pair).unwrap());
+}
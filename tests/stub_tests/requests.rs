@@ -0,0 +1,48 @@
+//! Serializes and deserializes the generated request types. Copied into the stub crate by
+//! `tests/compile.rs` along with the code generated for `0.3.0`.
+
+use serde_json::json;
+use starknet_core::types::*;
+
+mod common;
+
+use common::felt;
+
+#[test]
+fn serializes_by_name_params_as_object() {
+    let request = GetTransactionByHashRequest {
+        transaction_hash: felt(1),
+    };
+
+    let expected = json!({ "transaction_hash": felt(1) });
+    assert_eq!(serde_json::to_value(&request).unwrap(), expected);
+    assert_eq!(
+        serde_json::to_value(GetTransactionByHashRequestRef {
+            transaction_hash: &request.transaction_hash,
+        })
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn serializes_other_params_as_array() {
+    let request = GetStorageAtRequest {
+        contract_address: felt(1),
+        key: felt(2),
+        block_id: BlockId::default(),
+    };
+
+    let expected = json!([felt(1), felt(2), ""]);
+    assert_eq!(serde_json::to_value(&request).unwrap(), expected);
+    assert_eq!(
+        serde_json::to_value(GetStorageAtRequestRef {
+            contract_address: &request.contract_address,
+            key: &request.key,
+            block_id: &request.block_id,
+        })
+        .unwrap(),
+        expected
+    );
+    assert_eq!(serde_json::to_value(BlockNumberRequest).unwrap(), json!([]));
+}
@@ -0,0 +1,31 @@
+//! In-memory transport shared by the stub tests of generated clients. Copied into the stub crate
+//! by `tests/compile.rs` along with each test, which has to bring the `JsonRpcTransport` trait of
+//! the generated code into scope, as its path depends on the number of spec versions.
+
+use std::future::Future;
+
+use serde_json::Value;
+
+use super::JsonRpcTransport;
+
+#[derive(Debug, thiserror::Error)]
+#[error("transport failed")]
+pub struct TransportError;
+
+/// Transport answering each request with `handler`.
+pub struct InMemoryTransport<F>(pub F);
+
+// Clients have to be `Debug` for `unwrap_err`, which the handlers can't be
+impl<F> std::fmt::Debug for InMemoryTransport<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("InMemoryTransport")
+    }
+}
+
+impl<F: Fn(Value) -> Value + Send + Sync> JsonRpcTransport for InMemoryTransport<F> {
+    type Error = TransportError;
+
+    fn send(&self, request: Value) -> impl Future<Output = Result<Value, Self::Error>> + Send {
+        std::future::ready(Ok((self.0)(request)))
+    }
+}
@@ -0,0 +1,54 @@
+//! Converts values between spec versions. Copied into the stub crate by `tests/compile.rs` along
+//! with code generated with `--response-types --version-conversions`.
+
+use starknet_core::types::{conversions::VersionConversionError, v0_2, v0_3};
+
+mod common;
+
+use common::felt;
+
+#[test]
+fn matching_structs_are_converted() {
+    let invoke = v0_2::BroadcastedInvokeTransactionV1 {
+        max_fee: felt(1),
+        signature: vec![felt(2), felt(3)],
+        nonce: felt(4),
+        sender_address: felt(5),
+        calldata: vec![felt(6)],
+    };
+
+    let upgraded = v0_3::BroadcastedInvokeTransactionV1::from(invoke.clone());
+    assert_eq!(upgraded.signature, invoke.signature);
+    assert_eq!(upgraded.sender_address, invoke.sender_address);
+
+    let downgraded = v0_2::BroadcastedInvokeTransactionV1::from(upgraded);
+    assert_eq!(downgraded.calldata, invoke.calldata);
+}
+
+#[test]
+fn missing_variants_fail_to_convert() {
+    assert!(matches!(
+        v0_3::StarknetError::try_from(v0_2::StarknetError::BlockNotFound),
+        Ok(v0_3::StarknetError::BlockNotFound)
+    ));
+    assert!(matches!(
+        v0_3::StarknetError::try_from(v0_2::StarknetError::InvalidCallData),
+        Err(VersionConversionError {
+            type_name: "StarknetError",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn boolean_variants_convert_to_their_values() {
+    assert!(matches!(
+        v0_3::SyncingResponse::try_from(v0_2::SyncingResponse::Bool(false)),
+        Ok(v0_3::SyncingResponse::False)
+    ));
+    assert!(v0_3::SyncingResponse::try_from(v0_2::SyncingResponse::Bool(true)).is_err());
+    assert!(matches!(
+        v0_2::SyncingResponse::from(v0_3::SyncingResponse::False),
+        v0_2::SyncingResponse::Bool(false)
+    ));
+}
@@ -0,0 +1,146 @@
+//! Checks the module layout of code generated for several spec versions at once.
+
+mod common;
+
+use common::{generate, run, SPEC_VERSIONS};
+
+/// Gets the body of the top-level module `name`.
+fn module<'a>(code: &'a str, name: &str) -> &'a str {
+    let start = code
+        .find(&format!("\npub mod {name} {{\n"))
+        .unwrap_or_else(|| panic!("module `{name}` not found"));
+    let end = start + code[start..].find("\n}\n").unwrap();
+    &code[start..end]
+}
+
+#[test]
+fn single_version_is_not_wrapped_in_modules() {
+    let code = generate(&["--spec", "0.3.0"]);
+
+    assert!(!code.contains("pub mod "));
+    assert!(code.contains("\npub struct FeeEstimate {"));
+}
+
+#[test]
+fn identical_types_are_shared() {
+    let code = generate(&["--spec", "0.2.1,0.3.0"]);
+
+    let shared = module(&code, "shared");
+    let v0_2 = module(&code, "v0_2");
+    let v0_3 = module(&code, "v0_3");
+    assert!(!code.contains("pub mod v0_1 {"));
+
+    // Generated once in the shared module and re-exported by both versions
+    assert!(shared.contains("\n    pub struct FeeEstimate {"));
+    for versioned in [v0_2, v0_3] {
+        assert!(!versioned.contains("pub struct FeeEstimate {"));
+        assert!(versioned.contains("\n        FeeEstimate,\n"));
+    }
+
+    // Fields changed between versions
+    assert!(!shared.contains("pub struct InvokeTransactionReceipt {"));
+    assert!(v0_2.contains("\n    pub struct InvokeTransactionReceipt {"));
+    assert!(v0_3.contains("\n    pub struct InvokeTransactionReceipt {"));
+
+    // Error codes are implemented by each version's client
+    assert!(!shared.contains("pub enum StarknetError {"));
+}
+
+#[test]
+fn all_specs_selects_every_version() {
+    let code = generate(&["--all-specs"]);
+
+    module(&code, "shared");
+    for version in SPEC_VERSIONS {
+        let (major_minor, _) = version.rsplit_once('.').unwrap();
+        module(&code, &format!("v{}", major_minor.replace('.', "_")));
+    }
+}
+
+#[test]
+fn version_conversions_are_generated() {
+    let code = generate(&["--spec", "0.2.1,0.3.0", "--version-conversions"]);
+    let conversions = module(&code, "conversions");
+
+    assert!(conversions.contains(
+        "\n    impl From<v0_2::BroadcastedInvokeTransactionV1> for v0_3::BroadcastedInvokeTransactionV1 {"
+    ));
+    assert!(conversions.contains(
+        "\n    impl From<v0_3::BroadcastedInvokeTransactionV1> for v0_2::BroadcastedInvokeTransactionV1 {"
+    ));
+    assert!(
+        conversions.contains("\n    impl TryFrom<v0_2::StarknetError> for v0_3::StarknetError {")
+    );
+
+    // Shared types are the same in both versions
+    assert!(!conversions.contains("v0_2::FeeEstimate"));
+
+    // Changed types are reported instead, along with the types containing them
+    assert!(conversions.contains("\n    // - `MsgToL1`: field `from_address` added\n"));
+    assert!(conversions.contains(
+        "\n    // - `InvokeTransactionReceipt`: field `messages_sent` contains `MsgToL1` which needs manual conversion\n"
+    ));
+    assert!(!conversions.contains("impl From<v0_2::InvokeTransactionReceipt>"));
+}
+
+#[test]
+fn version_conversions_require_multiple_versions() {
+    let output = run(&["--spec", "0.3.0", "--version-conversions"]);
+
+    assert!(!output.status.success());
+    assert!(output
+        .stderr
+        .contains("Version conversions require more than one spec version"));
+}
+
+#[test]
+fn clients_share_transport() {
+    let code = generate(&[
+        "--spec",
+        "0.2.1,0.3.0",
+        "--response-types",
+        "--jsonrpc-envelopes",
+        "--client",
+        "--version-negotiation",
+    ]);
+
+    assert!(module(&code, "shared").contains("\n    pub trait JsonRpcTransport {"));
+    for name in ["v0_2", "v0_3"] {
+        let versioned = module(&code, name);
+        assert!(!versioned.contains("pub trait JsonRpcTransport"));
+        assert!(versioned.contains("\n        JsonRpcTransport,\n"));
+        assert!(versioned.contains("\n    pub const SPEC_VERSION: &str = "));
+        assert!(versioned.contains("\n    pub struct JsonRpcClient<T> {"));
+    }
+
+    let negotiation = module(&code, "negotiation");
+    assert!(negotiation.contains("\n    pub enum VersionedClient<T> {"));
+    // Both versions have the same read methods, write methods are never probed
+    assert!(negotiation.contains("\n    const PROBED_METHODS: [&str; 0] = [];\n"));
+    assert!(!negotiation.contains("starknet_addDeployTransaction"));
+}
+
+#[test]
+fn subcommands_require_single_version() {
+    let output = run(&["--spec", "0.2.1,0.3.0", "graph"]);
+
+    assert!(!output.status.success());
+    assert!(output
+        .stderr
+        .contains("Subcommands only support a single spec version"));
+}
+
+#[test]
+fn spec_file_requires_single_version() {
+    let output = run(&[
+        "--spec",
+        "0.2.1,0.3.0",
+        "--spec-file",
+        "tests/specs/synthetic.json",
+    ]);
+
+    assert!(!output.status.success());
+    assert!(output
+        .stderr
+        .contains("A spec file can only replace a single spec version"));
+}